@@ -4,10 +4,17 @@ use std::path::PathBuf;
 #[derive(Clone, Debug, Parser)]
 pub struct Cli {
     path: Option<PathBuf>,
+    /// Directory searched for imported modules, may be repeated.
+    #[arg(short = 'I', value_name = "DIR")]
+    include: Vec<PathBuf>,
 }
 
 impl Cli {
     pub fn get_path(&self) -> Option<&PathBuf> {
         self.path.as_ref()
     }
+
+    pub fn get_include(&self) -> &[PathBuf] {
+        &self.include
+    }
 }
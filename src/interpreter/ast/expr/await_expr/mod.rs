@@ -0,0 +1,39 @@
+use crate::interpreter::ast::expr::{Expr, ExprVisitor};
+use crate::interpreter::scanner::token::Token;
+use crate::utils::next_id;
+use std::ops::Deref;
+
+#[derive(Clone)]
+pub struct Await<T: 'static> {
+    id: u64,
+    keyword: Token,
+    value: Box<dyn Expr<T>>,
+}
+
+impl<T> Await<T> {
+    pub fn new(keyword: Token, value: Box<dyn Expr<T>>) -> Self {
+        Self {
+            id: next_id(),
+            keyword,
+            value,
+        }
+    }
+
+    pub fn get_keyword(&self) -> &Token {
+        &self.keyword
+    }
+
+    pub fn get_value(&self) -> &dyn Expr<T> {
+        self.value.deref()
+    }
+}
+
+impl<T: 'static + Clone> Expr<T> for Await<T> {
+    fn accept(&self, visitor: &mut dyn ExprVisitor<T>) -> T {
+        visitor.visit_await(self)
+    }
+
+    fn id(&self) -> u64 {
+        self.id
+    }
+}
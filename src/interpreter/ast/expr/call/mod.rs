@@ -3,12 +3,15 @@ use crate::interpreter::scanner::token::Token;
 use crate::utils::next_id;
 use std::ops::Deref;
 
+pub type NamedArg<T> = (Token, Box<dyn Expr<T>>);
+
 #[derive(Clone)]
 pub struct Call<T: 'static> {
     id: u64,
     callable: Box<dyn Expr<T>>,
     parens: Token,
     args: Vec<Box<dyn Expr<T>>>,
+    named_args: Vec<NamedArg<T>>,
 }
 
 impl<T> Call<T> {
@@ -18,9 +21,21 @@ impl<T> Call<T> {
             callable,
             parens,
             args,
+            named_args: vec![],
         }
     }
 
+    pub fn with_named_args(mut self, named_args: Vec<NamedArg<T>>) -> Self {
+        self.named_args = named_args;
+        self
+    }
+
+    /// Inserts `arg` before the other positional arguments.
+    pub fn with_first_arg(mut self, arg: Box<dyn Expr<T>>) -> Self {
+        self.args.insert(0, arg);
+        self
+    }
+
     pub fn get_callable(&self) -> &dyn Expr<T> {
         self.callable.deref()
     }
@@ -29,6 +44,13 @@ impl<T> Call<T> {
         self.args.iter().map(|arg| arg.deref()).collect()
     }
 
+    pub fn get_named_args(&self) -> Vec<(&Token, &dyn Expr<T>)> {
+        self.named_args
+            .iter()
+            .map(|(name, arg)| (name, arg.deref()))
+            .collect()
+    }
+
     pub fn get_token(&self) -> Token {
         self.parens.clone()
     }
@@ -0,0 +1,48 @@
+use crate::interpreter::ast::expr::{Expr, ExprVisitor};
+use crate::interpreter::ast::pattern::Pattern;
+use crate::interpreter::scanner::token::Token;
+use crate::utils::next_id;
+use std::ops::Deref;
+
+/// One `for pattern in iterable` or `if condition` clause of a comprehension.
+#[derive(Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum Clause<T: 'static> {
+    For(Token, Pattern, Box<dyn Expr<T>>),
+    If(Box<dyn Expr<T>>),
+}
+
+#[derive(Clone)]
+pub struct Comprehension<T: 'static> {
+    id: u64,
+    element: Box<dyn Expr<T>>,
+    clauses: Vec<Clause<T>>,
+}
+
+impl<T> Comprehension<T> {
+    pub fn new(element: Box<dyn Expr<T>>, clauses: Vec<Clause<T>>) -> Self {
+        Self {
+            id: next_id(),
+            element,
+            clauses,
+        }
+    }
+
+    pub fn get_element(&self) -> &dyn Expr<T> {
+        self.element.deref()
+    }
+
+    pub fn get_clauses(&self) -> &[Clause<T>] {
+        &self.clauses
+    }
+}
+
+impl<T: 'static + Clone> Expr<T> for Comprehension<T> {
+    fn accept(&self, visitor: &mut dyn ExprVisitor<T>) -> T {
+        visitor.visit_comprehension(self)
+    }
+
+    fn id(&self) -> u64 {
+        self.id
+    }
+}
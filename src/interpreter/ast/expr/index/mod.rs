@@ -0,0 +1,79 @@
+use crate::interpreter::ast::expr::{Expr, ExprVisitor};
+use crate::interpreter::scanner::token::Token;
+use crate::utils::next_id;
+use std::ops::Deref;
+
+pub type Bound<T> = Option<Box<dyn Expr<T>>>;
+
+#[derive(Clone)]
+pub struct Index<T: 'static> {
+    id: u64,
+    object: Box<dyn Expr<T>>,
+    bracket: Token,
+    start: Bound<T>,
+    end: Bound<T>,
+    step: Bound<T>,
+    slice: bool,
+}
+
+impl<T> Index<T> {
+    pub fn new(object: Box<dyn Expr<T>>, bracket: Token, index: Box<dyn Expr<T>>) -> Self {
+        Self {
+            id: next_id(),
+            object,
+            bracket,
+            start: Some(index),
+            end: None,
+            step: None,
+            slice: false,
+        }
+    }
+
+    pub fn slice(
+        object: Box<dyn Expr<T>>,
+        bracket: Token,
+        start: Bound<T>,
+        end: Bound<T>,
+        step: Bound<T>,
+    ) -> Self {
+        Self {
+            id: next_id(),
+            object,
+            bracket,
+            start,
+            end,
+            step,
+            slice: true,
+        }
+    }
+
+    pub fn get_object(&self) -> &dyn Expr<T> {
+        self.object.deref()
+    }
+
+    pub fn get_bracket(&self) -> &Token {
+        &self.bracket
+    }
+
+    pub fn get_bounds(&self) -> [Option<&dyn Expr<T>>; 3] {
+        [
+            self.start.as_deref(),
+            self.end.as_deref(),
+            self.step.as_deref(),
+        ]
+    }
+
+    pub fn is_slice(&self) -> bool {
+        self.slice
+    }
+}
+
+impl<T: 'static + Clone> Expr<T> for Index<T> {
+    fn accept(&self, visitor: &mut dyn ExprVisitor<T>) -> T {
+        visitor.visit_index(self)
+    }
+
+    fn id(&self) -> u64 {
+        self.id
+    }
+}
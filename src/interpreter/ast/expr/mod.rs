@@ -1,31 +1,41 @@
 use crate::interpreter::ast::expr::assignment::Assign;
+use crate::interpreter::ast::expr::await_expr::Await;
 use crate::interpreter::ast::expr::binary::Binary;
 use crate::interpreter::ast::expr::call::Call;
+use crate::interpreter::ast::expr::comprehension::Comprehension;
 use crate::interpreter::ast::expr::get::Get;
 use crate::interpreter::ast::expr::grouping::Grouping;
+use crate::interpreter::ast::expr::index::Index;
+use crate::interpreter::ast::expr::list::List;
 use crate::interpreter::ast::expr::literal::Literal;
 use crate::interpreter::ast::expr::logical::Logical;
+use crate::interpreter::ast::expr::range::Range;
 use crate::interpreter::ast::expr::self_expr::SelfExpr;
 use crate::interpreter::ast::expr::set::Set;
 use crate::interpreter::ast::expr::superclass::Super;
 use crate::interpreter::ast::expr::unary::Unary;
 use crate::interpreter::ast::expr::variable::Variable;
-use downcast_rs::{impl_downcast, Downcast};
-use crate::interpreter::ast::expr::list::List;
+use crate::interpreter::ast::expr::yield_expr::Yield;
+use downcast_rs::{Downcast, impl_downcast};
 
 pub mod assignment;
+pub mod await_expr;
 pub mod binary;
 pub mod call;
+pub mod comprehension;
 pub mod get;
 pub mod grouping;
+pub mod index;
+pub mod list;
 pub mod literal;
 pub mod logical;
+pub mod range;
 pub mod self_expr;
 pub mod set;
 pub mod superclass;
 pub mod unary;
 pub mod variable;
-pub mod list;
+pub mod yield_expr;
 
 pub trait ExprVisitor<T> {
     fn visit_binary(&mut self, binary: &Binary<T>) -> T;
@@ -41,6 +51,11 @@ pub trait ExprVisitor<T> {
     fn visit_self(&mut self, self_val: &SelfExpr) -> T;
     fn visit_super(&mut self, super_val: &Super) -> T;
     fn visit_list(&mut self, list: &List<T>) -> T;
+    fn visit_range(&mut self, range: &Range<T>) -> T;
+    fn visit_index(&mut self, index: &Index<T>) -> T;
+    fn visit_comprehension(&mut self, comprehension: &Comprehension<T>) -> T;
+    fn visit_yield(&mut self, yield_expr: &Yield<T>) -> T;
+    fn visit_await(&mut self, await_expr: &Await<T>) -> T;
 }
 
 pub trait Expr<T>: Downcast + CloneExpr<T> {
@@ -0,0 +1,42 @@
+use crate::interpreter::ast::expr::{Expr, ExprVisitor};
+use crate::interpreter::scanner::token::Token;
+use crate::interpreter::scanner::token::token_type::TokenType;
+use crate::utils::next_id;
+use std::ops::Deref;
+
+#[derive(Clone)]
+pub struct Range<T: 'static> {
+    id: u64,
+    start: Box<dyn Expr<T>>,
+    operator: Token,
+    end: Box<dyn Expr<T>>,
+}
+
+impl<T> Range<T> {
+    pub fn new(start: Box<dyn Expr<T>>, operator: Token, end: Box<dyn Expr<T>>) -> Self {
+        Self {
+            id: next_id(),
+            start,
+            operator,
+            end,
+        }
+    }
+
+    pub fn extract(&self) -> (&dyn Expr<T>, &Token, &dyn Expr<T>) {
+        (self.start.deref(), &self.operator, self.end.deref())
+    }
+
+    pub fn is_inclusive(&self) -> bool {
+        self.operator.get_type() == TokenType::DotDotEqual
+    }
+}
+
+impl<T: 'static + Clone> Expr<T> for Range<T> {
+    fn accept(&self, visitor: &mut dyn ExprVisitor<T>) -> T {
+        visitor.visit_range(self)
+    }
+
+    fn id(&self) -> u64 {
+        self.id
+    }
+}
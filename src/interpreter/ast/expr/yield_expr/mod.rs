@@ -0,0 +1,38 @@
+use crate::interpreter::ast::expr::{Expr, ExprVisitor};
+use crate::interpreter::scanner::token::Token;
+use crate::utils::next_id;
+
+#[derive(Clone)]
+pub struct Yield<T: 'static> {
+    id: u64,
+    keyword: Token,
+    value: Option<Box<dyn Expr<T>>>,
+}
+
+impl<T> Yield<T> {
+    pub fn new(keyword: Token, value: Option<Box<dyn Expr<T>>>) -> Self {
+        Self {
+            id: next_id(),
+            keyword,
+            value,
+        }
+    }
+
+    pub fn get_keyword(&self) -> &Token {
+        &self.keyword
+    }
+
+    pub fn get_value(&self) -> Option<&dyn Expr<T>> {
+        self.value.as_deref()
+    }
+}
+
+impl<T: 'static + Clone> Expr<T> for Yield<T> {
+    fn accept(&self, visitor: &mut dyn ExprVisitor<T>) -> T {
+        visitor.visit_yield(self)
+    }
+
+    fn id(&self) -> u64 {
+        self.id
+    }
+}
@@ -1,2 +1,3 @@
 pub mod expr;
+pub mod pattern;
 pub mod stmt;
@@ -0,0 +1,32 @@
+use crate::interpreter::scanner::token::Token;
+
+/// Binding target of a declaration: a plain name, a list pattern
+/// `[a, [b, c], ...rest]` or a field pattern `{name, age}`.
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    Name(Token),
+    List(Token, Vec<Pattern>, Option<Token>),
+    Fields(Token, Vec<Token>),
+}
+
+impl Pattern {
+    pub fn get_token(&self) -> Token {
+        match self {
+            Pattern::Name(token) | Pattern::List(token, _, _) | Pattern::Fields(token, _) => {
+                token.clone()
+            }
+        }
+    }
+
+    pub fn names(&self) -> Vec<Token> {
+        match self {
+            Pattern::Name(name) => vec![name.clone()],
+            Pattern::List(_, items, rest) => items
+                .iter()
+                .flat_map(Pattern::names)
+                .chain(rest.clone())
+                .collect(),
+            Pattern::Fields(_, fields) => fields.clone(),
+        }
+    }
+}
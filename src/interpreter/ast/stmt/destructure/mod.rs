@@ -0,0 +1,35 @@
+use crate::interpreter::ast::expr::Expr;
+use crate::interpreter::ast::pattern::Pattern;
+use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
+use std::ops::Deref;
+
+#[derive(Clone)]
+pub struct Destructure<T: 'static> {
+    pattern: Pattern,
+    initializer: Box<dyn Expr<T>>,
+    constant: bool,
+}
+
+impl<T> Destructure<T> {
+    pub fn new(pattern: Pattern, initializer: Box<dyn Expr<T>>, constant: bool) -> Self {
+        Self {
+            pattern,
+            initializer,
+            constant,
+        }
+    }
+
+    pub fn extract(&self) -> (&Pattern, &dyn Expr<T>) {
+        (&self.pattern, self.initializer.deref())
+    }
+
+    pub fn is_const(&self) -> bool {
+        self.constant
+    }
+}
+
+impl<T: 'static + Clone> Stmt<T> for Destructure<T> {
+    fn accept(&self, visitor: &mut dyn StmtVisitor<T>) -> T {
+        visitor.visit_destructure(self)
+    }
+}
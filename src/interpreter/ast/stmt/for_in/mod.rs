@@ -0,0 +1,46 @@
+use crate::interpreter::ast::expr::Expr;
+use crate::interpreter::ast::pattern::Pattern;
+use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
+use crate::interpreter::scanner::token::Token;
+use std::ops::Deref;
+
+type ExtractedForIn<'a, T> = (&'a Token, &'a Pattern, &'a dyn Expr<T>, &'a dyn Stmt<T>);
+
+#[derive(Clone)]
+pub struct ForIn<T: 'static> {
+    keyword: Token,
+    pattern: Pattern,
+    iterable: Box<dyn Expr<T>>,
+    body: Box<dyn Stmt<T>>,
+}
+
+impl<T> ForIn<T> {
+    pub fn new(
+        keyword: Token,
+        pattern: Pattern,
+        iterable: Box<dyn Expr<T>>,
+        body: Box<dyn Stmt<T>>,
+    ) -> Self {
+        Self {
+            keyword,
+            pattern,
+            iterable,
+            body,
+        }
+    }
+
+    pub fn extract(&self) -> ExtractedForIn<'_, T> {
+        (
+            &self.keyword,
+            &self.pattern,
+            self.iterable.deref(),
+            self.body.deref(),
+        )
+    }
+}
+
+impl<T: 'static + Clone> Stmt<T> for ForIn<T> {
+    fn accept(&self, visitor: &mut dyn StmtVisitor<T>) -> T {
+        visitor.visit_for_in(self)
+    }
+}
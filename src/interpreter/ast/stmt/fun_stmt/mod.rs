@@ -1,3 +1,4 @@
+use crate::interpreter::ast::expr::Expr;
 use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
 use crate::interpreter::scanner::token::Token;
 use crate::utils::next_id;
@@ -10,6 +11,9 @@ pub struct Fun<T: 'static> {
     name: Token,
     params: Vec<Token>,
     body: Vec<Box<dyn Stmt<T>>>,
+    generator: bool,
+    is_async: bool,
+    decorators: Vec<Box<dyn Expr<T>>>,
 }
 
 impl<T> Fun<T> {
@@ -19,16 +23,50 @@ impl<T> Fun<T> {
             name,
             params,
             body,
+            generator: false,
+            is_async: false,
+            decorators: vec![],
         }
     }
 
+    /// Marks a function whose body contains `yield`.
+    pub fn with_generator(mut self, generator: bool) -> Self {
+        self.generator = generator;
+        self
+    }
+
     pub fn extract(self) -> Extract<T> {
         (self.id, self.name, self.params, self.body)
     }
 
+    /// Marks a function declared with `async fun`.
+    pub fn with_async(mut self, is_async: bool) -> Self {
+        self.is_async = is_async;
+        self
+    }
+
+    /// Attaches the `@decorator` expressions written above the declaration,
+    /// outermost first.
+    pub fn with_decorators(mut self, decorators: Vec<Box<dyn Expr<T>>>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
+    pub fn get_decorators(&self) -> &Vec<Box<dyn Expr<T>>> {
+        &self.decorators
+    }
+
     pub fn get_name(&self) -> Token {
         self.name.clone()
     }
+
+    pub fn is_generator(&self) -> bool {
+        self.generator
+    }
+
+    pub fn is_async(&self) -> bool {
+        self.is_async
+    }
 }
 
 impl<T: 'static + Clone> Stmt<T> for Fun<T> {
@@ -7,11 +7,28 @@ use std::ops::Deref;
 pub struct Let<T: 'static> {
     ident: Token,
     initializer: Option<Box<dyn Expr<T>>>,
+    constant: bool,
 }
 
 impl<T> Let<T> {
     pub fn new(ident: Token, initializer: Option<Box<dyn Expr<T>>>) -> Self {
-        Self { ident, initializer }
+        Self {
+            ident,
+            initializer,
+            constant: false,
+        }
+    }
+
+    pub fn constant(ident: Token, initializer: Box<dyn Expr<T>>) -> Self {
+        Self {
+            ident,
+            initializer: Some(initializer),
+            constant: true,
+        }
+    }
+
+    pub fn is_const(&self) -> bool {
+        self.constant
     }
 
     pub fn get_ident(&self) -> Token {
@@ -1,25 +1,34 @@
 use crate::interpreter::ast::stmt::block::Block;
 use crate::interpreter::ast::stmt::class::Class;
+use crate::interpreter::ast::stmt::destructure::Destructure;
+use crate::interpreter::ast::stmt::enum_stmt::Enum;
 use crate::interpreter::ast::stmt::export_stmt::Export;
+use crate::interpreter::ast::stmt::for_in::ForIn;
 use crate::interpreter::ast::stmt::fun_stmt::Fun;
 use crate::interpreter::ast::stmt::if_stmt::If;
 use crate::interpreter::ast::stmt::let_stmt::Let;
 use crate::interpreter::ast::stmt::print::Print;
 use crate::interpreter::ast::stmt::return_stmt::Return;
 use crate::interpreter::ast::stmt::stmt_expr::StmtExpr;
+use crate::interpreter::ast::stmt::struct_stmt::Struct;
 use crate::interpreter::ast::stmt::use_stmt::Use;
 use crate::interpreter::ast::stmt::while_stmt::While;
-use downcast_rs::{impl_downcast, Downcast};
+use crate::interpreter::scanner::token::Token;
+use downcast_rs::{Downcast, impl_downcast};
 
 pub mod block;
 pub mod class;
+pub mod destructure;
+pub mod enum_stmt;
 pub mod export_stmt;
+pub mod for_in;
 pub mod fun_stmt;
 pub mod if_stmt;
 pub mod let_stmt;
 pub mod print;
 pub mod return_stmt;
 pub mod stmt_expr;
+pub mod struct_stmt;
 pub mod use_stmt;
 pub mod while_stmt;
 
@@ -35,6 +44,10 @@ pub trait StmtVisitor<T> {
     fn visit_class(&mut self, stmt: &Class<T>) -> T;
     fn visit_export(&mut self, stmt: &Export<T>) -> T;
     fn visit_use(&mut self, stmt: &Use<T>) -> T;
+    fn visit_struct(&mut self, stmt: &Struct<T>) -> T;
+    fn visit_enum(&mut self, stmt: &Enum) -> T;
+    fn visit_destructure(&mut self, stmt: &Destructure<T>) -> T;
+    fn visit_for_in(&mut self, stmt: &ForIn<T>) -> T;
 }
 
 pub trait CloneStmt<T> {
@@ -47,6 +60,44 @@ pub trait Stmt<T>: Downcast + CloneStmt<T> {
 
 impl_downcast!(Stmt<T>);
 
+/// Names a declaration binds in its scope, looking through `export`.
+pub fn declared_names<T: 'static + Clone>(stmt: &dyn Stmt<T>) -> Vec<Token> {
+    if let Some(export) = stmt.downcast_ref::<Export<T>>() {
+        return declared_names(export.extract().1);
+    }
+    if let Some(stmt) = stmt.downcast_ref::<Let<T>>() {
+        return vec![stmt.get_ident()];
+    }
+    if let Some(stmt) = stmt.downcast_ref::<Destructure<T>>() {
+        return stmt.extract().0.names();
+    }
+    if let Some(stmt) = stmt.downcast_ref::<Fun<T>>() {
+        return vec![stmt.get_name()];
+    }
+    if let Some(stmt) = stmt.downcast_ref::<Class<T>>() {
+        return vec![stmt.extract().0.clone()];
+    }
+    if let Some(stmt) = stmt.downcast_ref::<Struct<T>>() {
+        return vec![stmt.extract().0.clone()];
+    }
+    if let Some(stmt) = stmt.downcast_ref::<Enum>() {
+        return vec![stmt.extract().0.clone()];
+    }
+    if let Some(stmt) = stmt.downcast_ref::<Use<T>>() {
+        return stmt.bound_names();
+    }
+    vec![]
+}
+
+/// Names declared by the `export` statements of a module, re-exports included.
+pub fn exported_names<T: 'static + Clone>(stmts: &[Box<dyn Stmt<T>>]) -> Vec<Token> {
+    stmts
+        .iter()
+        .filter(|stmt| stmt.is::<Export<T>>())
+        .flat_map(|stmt| declared_names(stmt.as_ref()))
+        .collect()
+}
+
 impl<T, R> CloneStmt<T> for R
 where
     R: 'static + Stmt<T> + Clone,
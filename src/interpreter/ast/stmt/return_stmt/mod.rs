@@ -22,4 +22,4 @@ impl<T: 'static + Clone> Stmt<T> for Return<T> {
     fn accept(&self, visitor: &mut dyn StmtVisitor<T>) -> T {
         visitor.visit_return(self)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,35 @@
+use crate::interpreter::ast::expr::Expr;
+use crate::interpreter::ast::stmt::fun_stmt::Fun;
+use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
+use crate::interpreter::scanner::token::Token;
+
+pub type StructField<T> = (Token, Option<Box<dyn Expr<T>>>);
+
+type ExtractedStruct<'a, T> = (&'a Token, &'a Vec<StructField<T>>, &'a Vec<Fun<T>>);
+
+#[derive(Clone)]
+pub struct Struct<T: 'static> {
+    name: Token,
+    fields: Vec<StructField<T>>,
+    methods: Vec<Fun<T>>,
+}
+
+impl<T> Struct<T> {
+    pub fn new(name: Token, fields: Vec<StructField<T>>, methods: Vec<Fun<T>>) -> Self {
+        Self {
+            name,
+            fields,
+            methods,
+        }
+    }
+
+    pub fn extract(&self) -> ExtractedStruct<'_, T> {
+        (&self.name, &self.fields, &self.methods)
+    }
+}
+
+impl<T: 'static + Clone> Stmt<T> for Struct<T> {
+    fn accept(&self, visitor: &mut dyn StmtVisitor<T>) -> T {
+        visitor.visit_struct(self)
+    }
+}
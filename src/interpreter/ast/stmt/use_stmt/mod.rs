@@ -1,22 +1,98 @@
 use crate::interpreter::ast::expr::Expr;
-use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
+use crate::interpreter::ast::stmt::{Stmt, StmtVisitor, exported_names};
 use crate::interpreter::scanner::token::Token;
 use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+/// What a `use` binds in the importing scope.
+#[derive(Clone)]
+pub enum Import {
+    /// `use * from "path";` splices every export into the importer.
+    All,
+    /// `use "path" as name;` binds a module object, `name` defaults to the
+    /// last segment of the path.
+    Module(Token),
+    /// `use { a, b as c } from "path";` binds the listed exports, each pair
+    /// is the exported name and the name it is bound to.
+    Names(Vec<(Token, Token)>),
+}
+
+/// Loaded module shared by every `use` of the same file.
+#[derive(Clone)]
+pub struct ModuleAst<T: 'static> {
+    path: PathBuf,
+    body: Rc<Vec<Box<dyn Stmt<T>>>>,
+}
+
+impl<T> ModuleAst<T> {
+    pub fn new(path: PathBuf, body: Vec<Box<dyn Stmt<T>>>) -> Self {
+        Self {
+            path,
+            body: Rc::new(body),
+        }
+    }
+
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get_body(&self) -> &Vec<Box<dyn Stmt<T>>> {
+        &self.body
+    }
+}
 
 #[derive(Clone)]
 pub struct Use<T: 'static> {
     name: Token,
     expr: Box<dyn Expr<T>>,
+    import: Import,
+    module: Option<ModuleAst<T>>,
 }
 
 impl<T> Use<T> {
-    pub fn new(name: Token, expr: Box<dyn Expr<T>>) -> Self {
-        Self { name, expr }
+    pub fn new(name: Token, expr: Box<dyn Expr<T>>, import: Import) -> Self {
+        Self {
+            name,
+            expr,
+            import,
+            module: None,
+        }
+    }
+
+    /// Attaches the loaded module.
+    pub fn with_module(mut self, module: ModuleAst<T>) -> Self {
+        self.module = Some(module);
+        self
     }
 
     pub fn extract(&self) -> (&Token, &dyn Expr<T>) {
         (&self.name, self.expr.deref())
     }
+
+    pub fn get_import(&self) -> &Import {
+        &self.import
+    }
+
+    pub fn get_module(&self) -> Option<&ModuleAst<T>> {
+        self.module.as_ref()
+    }
+}
+
+impl<T: 'static + Clone> Use<T> {
+    /// Names bound in the importing scope, `use *` only knows them once its
+    /// module is attached.
+    pub fn bound_names(&self) -> Vec<Token> {
+        match &self.import {
+            Import::All => self
+                .module
+                .as_ref()
+                .map(|module| exported_names(module.get_body()))
+                .unwrap_or_default(),
+            Import::Module(alias) => vec![alias.clone()],
+            Import::Names(names) => names.iter().map(|(_, alias)| alias.clone()).collect(),
+        }
+    }
 }
 
 impl<T: 'static + Clone> Stmt<T> for Use<T> {
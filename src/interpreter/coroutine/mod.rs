@@ -0,0 +1,289 @@
+use crate::interpreter::Interpreter;
+use crate::interpreter::ast::expr::Expr;
+use crate::interpreter::ast::expr::assignment::Assign;
+use crate::interpreter::ast::expr::await_expr::Await;
+use crate::interpreter::ast::expr::yield_expr::Yield;
+use crate::interpreter::ast::pattern::Pattern;
+use crate::interpreter::ast::stmt::Stmt;
+use crate::interpreter::ast::stmt::block::Block;
+use crate::interpreter::ast::stmt::destructure::Destructure;
+use crate::interpreter::ast::stmt::for_in::ForIn;
+use crate::interpreter::ast::stmt::if_stmt::If;
+use crate::interpreter::ast::stmt::let_stmt::Let;
+use crate::interpreter::ast::stmt::print::Print;
+use crate::interpreter::ast::stmt::return_stmt::Return;
+use crate::interpreter::ast::stmt::stmt_expr::StmtExpr;
+use crate::interpreter::ast::stmt::while_stmt::While;
+use crate::interpreter::environment::Environment;
+use crate::interpreter::error::{InterpreterError, Result};
+use crate::interpreter::object::Object;
+use crate::interpreter::object::iterator::NativeIterator;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type Env = Option<Rc<RefCell<Environment>>>;
+type Statement = Box<dyn Stmt<Result<Object>>>;
+
+/// Outcome of resuming a coroutine.
+pub enum Step {
+    Yield(Object),
+    Await(Object),
+    Complete(Object),
+}
+
+/// Saved position inside a statement that contains a suspension point.
+enum Frame {
+    Block {
+        stmts: Vec<Statement>,
+        index: usize,
+        previous: Env,
+    },
+    While(While<Result<Object>>),
+    ForIn {
+        iterator: NativeIterator,
+        pattern: Box<Pattern>,
+        body: Statement,
+        previous: Env,
+    },
+    /// Statement suspended at its `yield` or `await`; it is executed again on
+    /// resume with the sent value standing in for that expression.
+    Resume(Statement),
+}
+
+/// Function body whose execution can be suspended at `yield` or `await` and
+/// resumed later. Statements without a suspension point run on the regular
+/// interpreter, the rest are unrolled into an explicit stack of frames.
+pub struct Coroutine {
+    frames: Vec<Frame>,
+    env: Env,
+}
+
+impl Coroutine {
+    pub fn new(body: Vec<Statement>, env: Rc<RefCell<Environment>>) -> Self {
+        Self {
+            frames: vec![Frame::Block {
+                stmts: body,
+                index: 0,
+                previous: Some(env.clone()),
+            }],
+            env: Some(env),
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn finish(&mut self) {
+        self.frames.clear();
+    }
+
+    /// Runs until the next suspension point or the end of the body. `sent`
+    /// becomes the value of the `yield` or `await` the coroutine is suspended
+    /// at.
+    pub fn resume(&mut self, interpreter: &mut Interpreter, sent: Object) -> Result<Step> {
+        if self.is_finished() {
+            return Ok(Step::Complete(Object::Nil));
+        }
+
+        let caller = std::mem::replace(&mut interpreter.env, self.env.take());
+        let res = self.run(interpreter, sent);
+        self.env = std::mem::replace(&mut interpreter.env, caller);
+
+        match res {
+            Ok(Some(step)) => Ok(step),
+            Ok(None) => {
+                self.finish();
+                Ok(Step::Complete(Object::Nil))
+            }
+            Err(InterpreterError::Return(value)) => {
+                self.finish();
+                Ok(Step::Complete(value))
+            }
+            Err(err) => {
+                self.finish();
+                Err(err)
+            }
+        }
+    }
+
+    /// The `yield` or `await` of a statement that suspends by itself:
+    /// `yield x;`, `a = yield x;`, `let a = yield x;`, `let [a, b] = yield x;`,
+    /// `return yield x;` or `print yield x;`.
+    pub fn suspend_point(stmt: &dyn Stmt<Result<Object>>) -> Option<&dyn Expr<Result<Object>>> {
+        let expr = if let Some(stmt) = stmt.downcast_ref::<StmtExpr<Result<Object>>>() {
+            let expr = stmt.expr();
+            match expr.downcast_ref::<Assign<Result<Object>>>() {
+                Some(assign) => assign.get_value(),
+                None => expr,
+            }
+        } else if let Some(stmt) = stmt.downcast_ref::<Let<Result<Object>>>() {
+            stmt.get_initializer()?
+        } else if let Some(stmt) = stmt.downcast_ref::<Destructure<Result<Object>>>() {
+            stmt.extract().1
+        } else if let Some(stmt) = stmt.downcast_ref::<Return<Result<Object>>>() {
+            stmt.extract().1?
+        } else if let Some(stmt) = stmt.downcast_ref::<Print<Result<Object>>>() {
+            stmt.expr()
+        } else {
+            return None;
+        };
+        (expr.is::<Yield<Result<Object>>>() || expr.is::<Await<Result<Object>>>()).then_some(expr)
+    }
+
+    fn suspends(stmt: &dyn Stmt<Result<Object>>) -> bool {
+        if let Some(block) = stmt.downcast_ref::<Block<Result<Object>>>() {
+            return block.get_stmts().into_iter().any(Self::suspends);
+        }
+        if let Some(stmt) = stmt.downcast_ref::<If<Result<Object>>>() {
+            let (_, then, else_) = stmt.extract();
+            return Self::suspends(then) || else_.is_some_and(Self::suspends);
+        }
+        if let Some(stmt) = stmt.downcast_ref::<While<Result<Object>>>() {
+            return Self::suspends(stmt.extract().1);
+        }
+        if let Some(stmt) = stmt.downcast_ref::<ForIn<Result<Object>>>() {
+            return Self::suspends(stmt.extract().3);
+        }
+        Self::suspend_point(stmt).is_some()
+    }
+
+    fn run(&mut self, interpreter: &mut Interpreter, sent: Object) -> Result<Option<Step>> {
+        let mut sent = Some(sent);
+        while let Some(frame) = self.frames.last_mut() {
+            match frame {
+                Frame::Resume(stmt) => {
+                    let stmt = stmt.clone();
+                    self.frames.pop();
+                    interpreter.resumed = sent.take();
+                    let res = interpreter.execute(stmt.as_ref());
+                    interpreter.resumed = None;
+                    res?;
+                }
+                Frame::Block {
+                    stmts,
+                    index,
+                    previous,
+                } => {
+                    if *index == stmts.len() {
+                        interpreter.env = previous.clone();
+                        self.frames.pop();
+                        continue;
+                    }
+                    let stmt = stmts[*index].clone();
+                    *index += 1;
+                    if let Some(step) = self.enter(interpreter, stmt)? {
+                        return Ok(Some(step));
+                    }
+                }
+                Frame::While(stmt) => {
+                    let (cond, body) = stmt.extract();
+                    let body = body.clone_box();
+                    if interpreter.evaluate(cond)? != Object::Bool(true) {
+                        self.frames.pop();
+                    } else if let Some(step) = self.enter(interpreter, body)? {
+                        return Ok(Some(step));
+                    }
+                }
+                Frame::ForIn {
+                    iterator,
+                    pattern,
+                    body,
+                    previous,
+                } => {
+                    let (iterator, pattern, body) =
+                        (iterator.clone(), pattern.clone(), body.clone());
+                    interpreter.env = previous.clone();
+                    let Some(value) = iterator.next(interpreter)? else {
+                        self.frames.pop();
+                        continue;
+                    };
+
+                    let env = Environment::new(interpreter.env.clone());
+                    interpreter.env = Some(Rc::new(RefCell::new(env)));
+                    interpreter.bind_pattern(&pattern, value, false)?;
+                    if let Some(step) = self.enter(interpreter, body)? {
+                        return Ok(Some(step));
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Starts executing `stmt`, returning how it suspended if it did.
+    fn enter(&mut self, interpreter: &mut Interpreter, stmt: Statement) -> Result<Option<Step>> {
+        if !Self::suspends(stmt.as_ref()) {
+            interpreter.execute(stmt.as_ref())?;
+            return Ok(None);
+        }
+
+        if let Some(block) = stmt.downcast_ref::<Block<Result<Object>>>() {
+            let previous = interpreter.env.clone();
+            let env = Environment::new(previous.clone());
+            interpreter.env = Some(Rc::new(RefCell::new(env)));
+            self.frames.push(Frame::Block {
+                stmts: block
+                    .get_stmts()
+                    .into_iter()
+                    .map(|s| s.clone_box())
+                    .collect(),
+                index: 0,
+                previous,
+            });
+            return Ok(None);
+        }
+
+        if let Some(stmt) = stmt.downcast_ref::<If<Result<Object>>>() {
+            let (cond, then, else_) = stmt.extract();
+            if interpreter.evaluate(cond)? == Object::Bool(true) {
+                return self.enter(interpreter, then.clone_box());
+            }
+            return match else_ {
+                Some(else_) => self.enter(interpreter, else_.clone_box()),
+                None => Ok(None),
+            };
+        }
+
+        if let Some(stmt) = stmt.downcast_ref::<While<Result<Object>>>() {
+            self.frames.push(Frame::While(stmt.clone()));
+            return Ok(None);
+        }
+
+        if let Some(stmt) = stmt.downcast_ref::<ForIn<Result<Object>>>() {
+            let (keyword, pattern, iterable, body) = stmt.extract();
+            let iterable = interpreter.evaluate(iterable)?;
+            let iterator = match interpreter.iterator(iterable) {
+                Ok(iterator) => iterator,
+                Err(err) => {
+                    return Interpreter::handle_runtime_error(keyword.clone(), Err(err))
+                        .map(|_| None);
+                }
+            };
+            self.frames.push(Frame::ForIn {
+                iterator,
+                pattern: Box::new(pattern.clone()),
+                body: body.clone_box(),
+                previous: interpreter.env.clone(),
+            });
+            return Ok(None);
+        }
+
+        let Some(expr) = Self::suspend_point(stmt.as_ref()) else {
+            panic!("Interpreter bug");
+        };
+        let step = if let Some(await_expr) = expr.downcast_ref::<Await<Result<Object>>>() {
+            Step::Await(interpreter.evaluate(await_expr.get_value())?)
+        } else {
+            match expr
+                .downcast_ref::<Yield<Result<Object>>>()
+                .and_then(Yield::get_value)
+            {
+                Some(value) => Step::Yield(interpreter.evaluate(value)?),
+                None => Step::Yield(Object::Nil),
+            }
+        };
+        self.frames.push(Frame::Resume(stmt));
+        Ok(Some(step))
+    }
+}
@@ -3,12 +3,13 @@ use crate::interpreter::error::{RuntimeError, RuntimeErrorType};
 use crate::interpreter::object::Object;
 use crate::interpreter::scanner::token::Token;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
 #[derive(Debug)]
 pub struct Environment {
     values: HashMap<String, Option<Object>>,
+    constants: HashSet<String>,
     enclosing: Option<Rc<RefCell<Environment>>>,
 }
 
@@ -22,14 +23,57 @@ impl Environment {
     pub fn new(enclosing: Option<Rc<RefCell<Environment>>>) -> Self {
         Self {
             values: Default::default(),
+            constants: Default::default(),
             enclosing,
         }
     }
 
-    pub fn define(&mut self, name: &str, value: Option<Object>) {
+    /// Binds `name` in this scope, a constant of the scope can't be
+    /// redeclared.
+    pub fn define(&mut self, name: &str, value: Option<Object>) -> Result<()> {
+        if self.constants.contains(name) {
+            return Err(RuntimeErrorType::RedeclareConstant(name.to_string()).into());
+        }
+        self.values.insert(name.to_string(), value);
+        Ok(())
+    }
+
+    /// Binds a builtin in the fresh global scope, where nothing is constant yet.
+    pub fn define_builtin(&mut self, name: &str, value: Option<Object>) {
         self.values.insert(name.to_string(), value);
     }
 
+    /// Replaces a binding imported from a module that was reloaded, taking
+    /// over whether the export is constant.
+    pub fn rebind(&mut self, name: &str, value: Object, constant: bool) {
+        self.values.insert(name.to_string(), Some(value));
+        if constant {
+            self.constants.insert(name.to_string());
+        } else {
+            self.constants.remove(name);
+        }
+    }
+
+    pub fn define_const(&mut self, name: &str, value: Object) {
+        self.values.insert(name.to_string(), Some(value));
+        self.constants.insert(name.to_string());
+    }
+
+    pub fn is_const(&self, name: &str) -> bool {
+        self.constants.contains(name)
+    }
+
+    fn check_not_const(&self, name: &Token) -> Result<()> {
+        if self.constants.contains(name.get_lexeme()) {
+            return Err(RuntimeError::new(
+                name.clone(),
+                RuntimeErrorType::AssignToConstant(name.get_lexeme().to_string()),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
     pub fn get(&self, name: &Token) -> Result<Object> {
         if let Some(value) = self.values.get(name.get_lexeme()) {
             return match value {
@@ -75,6 +119,7 @@ impl Environment {
 
     pub fn assign(&mut self, name: &Token, value: Object) -> Result<Object> {
         if self.values.contains_key(name.get_lexeme()) {
+            self.check_not_const(name)?;
             self.values
                 .insert(name.get_lexeme().to_string(), Some(value.clone()));
             return Ok(value);
@@ -98,6 +143,7 @@ impl Environment {
         value: Object,
     ) -> Result<Object> {
         if let Some(environment) = Environment::ancestor(env, distance) {
+            environment.borrow().check_not_const(name)?;
             environment
                 .borrow_mut()
                 .values
@@ -106,7 +152,7 @@ impl Environment {
         }
         Err(RuntimeError::new(name.clone(), RuntimeErrorType::BugEnvironmentNotInit).into())
     }
-    
+
     pub fn get_enclosing(&self) -> Option<Rc<RefCell<Environment>>> {
         self.enclosing.clone()
     }
@@ -1,9 +1,10 @@
+use crate::interpreter::Interpreter;
 use crate::interpreter::exporter::error::ExporterError;
 use crate::interpreter::object::Object;
+use crate::interpreter::package::error::PackageError;
 use crate::interpreter::parser::error::ParserError;
 use crate::interpreter::scanner::error::ScannerError;
 use crate::interpreter::scanner::token::Token;
-use crate::interpreter::Interpreter;
 use std::fmt::{Display, Formatter};
 use std::num::ParseFloatError;
 use thiserror::Error;
@@ -19,6 +20,8 @@ pub enum InterpreterError {
     #[error("{0}")]
     ExporterError(ExporterError),
     #[error("{0}")]
+    PackageError(PackageError),
+    #[error("{0}")]
     RuntimeError(RuntimeError),
     #[error("{0}")]
     RuntimeErrorType(RuntimeErrorType),
@@ -87,6 +90,31 @@ pub enum RuntimeErrorType {
     OnlyInstancesHaveProperties,
     UndefinedProperty(String),
     SuperclassMustBeClass,
+    NamedArgsNotSupported,
+    UnexpectedNamedArg(String),
+    DuplicateArg(String),
+    UndefinedVariant(String, String),
+    PrivateMemberAccess(String),
+    MethodWithoutInstance(String),
+    AssignToConstant(String),
+    RedeclareConstant(String),
+    CannotDestructure(String, String),
+    DestructureLengthMismatch(usize, usize),
+    NotIterable(String),
+    RangeBoundsMustBeNumbers(String, String),
+    RangeStepMustBeNonZero,
+    CannotCheckMembership(String, String),
+    CannotIndex(String),
+    IndexMustBeNumber(String),
+    IndexMustBeInteger(String),
+    SliceStepMustBeNonZero,
+    GeneratorAlreadyRunning(String),
+    BugYieldOutsideGenerator,
+    NotATask(String),
+    TaskNeverCompletes(String),
+    UndefinedExport(String, String),
+    ModuleNotLoaded(String),
+    ExpectedString(String),
 }
 
 impl Display for RuntimeErrorType {
@@ -129,6 +157,85 @@ impl Display for RuntimeErrorType {
             }
             RuntimeErrorType::UndefinedProperty(name) => write!(f, "Undefined property '{}'", name),
             RuntimeErrorType::SuperclassMustBeClass => write!(f, "Superclass must be class"),
+            RuntimeErrorType::NamedArgsNotSupported => {
+                write!(f, "This callable doesn't accept named arguments")
+            }
+            RuntimeErrorType::UnexpectedNamedArg(name) => {
+                write!(f, "Unexpected named argument '{}'", name)
+            }
+            RuntimeErrorType::DuplicateArg(name) => {
+                write!(f, "Argument '{}' is passed more than once", name)
+            }
+            RuntimeErrorType::UndefinedVariant(enumeration, name) => {
+                write!(f, "Enum '{}' has no variant '{}'", enumeration, name)
+            }
+            RuntimeErrorType::MethodWithoutInstance(name) => {
+                write!(
+                    f,
+                    "Decorated method '{}' called outside a call on its instance",
+                    name
+                )
+            }
+            RuntimeErrorType::PrivateMemberAccess(name) => {
+                write!(
+                    f,
+                    "Private member '{}' is only accessible inside its class",
+                    name
+                )
+            }
+            RuntimeErrorType::AssignToConstant(name) => {
+                write!(f, "Cannot assign to constant '{}'", name)
+            }
+            RuntimeErrorType::RedeclareConstant(name) => {
+                write!(f, "Cannot redeclare constant '{}'", name)
+            }
+            RuntimeErrorType::CannotDestructure(ty, pattern) => {
+                write!(f, "Cannot destructure '{}' with a {} pattern", ty, pattern)
+            }
+            RuntimeErrorType::DestructureLengthMismatch(expected, got) => {
+                write!(
+                    f,
+                    "Expected {} elements to destructure, got {}",
+                    expected, got
+                )
+            }
+            RuntimeErrorType::NotIterable(ty) => write!(f, "Type '{}' is not iterable", ty),
+            RuntimeErrorType::RangeBoundsMustBeNumbers(ty1, ty2) => {
+                write!(
+                    f,
+                    "Range bounds must be numbers, got '{}' and '{}'",
+                    ty1, ty2
+                )
+            }
+            RuntimeErrorType::RangeStepMustBeNonZero => write!(f, "Range step must be non-zero"),
+            RuntimeErrorType::CannotCheckMembership(ty1, ty2) => {
+                write!(f, "Cannot check if '{}' is in '{}'", ty1, ty2)
+            }
+            RuntimeErrorType::CannotIndex(ty) => write!(f, "Type '{}' cannot be indexed", ty),
+            RuntimeErrorType::IndexMustBeNumber(ty) => {
+                write!(f, "Index must be a number, got '{}'", ty)
+            }
+            RuntimeErrorType::IndexMustBeInteger(value) => {
+                write!(f, "Index must be a whole number, got '{}'", value)
+            }
+            RuntimeErrorType::SliceStepMustBeNonZero => write!(f, "Slice step must be non-zero"),
+            RuntimeErrorType::GeneratorAlreadyRunning(name) => {
+                write!(f, "Generator '{}' is already running", name)
+            }
+            RuntimeErrorType::BugYieldOutsideGenerator => {
+                write!(f, "Bug 'yield' evaluated outside of a generator")
+            }
+            RuntimeErrorType::NotATask(ty) => write!(f, "Expected a task, got '{}'", ty),
+            RuntimeErrorType::TaskNeverCompletes(name) => {
+                write!(f, "Task '{}' can never complete", name)
+            }
+            RuntimeErrorType::UndefinedExport(module, name) => {
+                write!(f, "Module '{}' doesn't export '{}'", module, name)
+            }
+            RuntimeErrorType::ModuleNotLoaded(path) => {
+                write!(f, "Module '{}' isn't loaded", path)
+            }
+            RuntimeErrorType::ExpectedString(ty) => write!(f, "Expected a string, got '{}'", ty),
         }
     }
 }
@@ -0,0 +1,151 @@
+use crate::interpreter::Interpreter;
+use crate::interpreter::coroutine::Step;
+use crate::interpreter::error::{Result, RuntimeErrorType};
+use crate::interpreter::object::Object;
+use crate::interpreter::object::task::{Task, TaskKind};
+use std::collections::VecDeque;
+
+/// Single-threaded scheduler behind `async fun`, `await`, `sleep` and
+/// `setTimeout`. Time is simulated: when no task is ready the clock jumps to
+/// the next timer, so a run always produces the same interleaving.
+#[derive(Default)]
+pub struct EventLoop {
+    now: f64,
+    sequence: u64,
+    ready: VecDeque<(Task, Object)>,
+    timers: Vec<(f64, u64, Task)>,
+}
+
+impl EventLoop {
+    /// Milliseconds of loop time elapsed since the interpreter started.
+    pub fn now(&self) -> f64 {
+        self.now
+    }
+
+    /// Schedules a task that has not started yet.
+    pub fn start(&mut self, task: &Task) {
+        if task.is_started() {
+            return;
+        }
+        task.mark_started();
+        match task.delay() {
+            Some(delay) => {
+                self.sequence += 1;
+                self.timers
+                    .push((self.now + delay.max(0.0), self.sequence, task.clone()));
+            }
+            None => self.ready.push_back((task.clone(), Object::Nil)),
+        }
+    }
+
+    fn next_timer(&mut self) -> Option<Task> {
+        let (index, _) =
+            self.timers
+                .iter()
+                .enumerate()
+                .min_by(|(_, (a, a_seq, _)), (_, (b, b_seq, _))| {
+                    a.total_cmp(b).then(a_seq.cmp(b_seq))
+                })?;
+        let (at, _, task) = self.timers.remove(index);
+        self.now = self.now.max(at);
+        Some(task)
+    }
+
+    fn complete(&mut self, task: &Task, value: Object) {
+        for waiter in task.complete(value.clone()) {
+            self.ready.push_back((waiter, value.clone()));
+        }
+    }
+
+    /// Starts `task` and runs the loop until it completes.
+    pub fn block_on(interpreter: &mut Interpreter, task: &Task) -> Result<Object> {
+        interpreter.event_loop.start(task);
+        Self::run(interpreter, Some(task))?;
+        task.get_result()
+            .ok_or_else(|| RuntimeErrorType::TaskNeverCompletes(task.get_name()).into())
+    }
+
+    /// Runs ready tasks and fires timers until `until` completes or there is
+    /// nothing left to do.
+    pub fn run(interpreter: &mut Interpreter, until: Option<&Task>) -> Result<()> {
+        loop {
+            if until.is_some_and(Task::is_done) {
+                return Ok(());
+            }
+            if let Some((task, sent)) = interpreter.event_loop.ready.pop_front() {
+                Self::step(interpreter, task, sent)?;
+                continue;
+            }
+            match interpreter.event_loop.next_timer() {
+                Some(task) => Self::fire(interpreter, task)?,
+                None => return Ok(()),
+            }
+        }
+    }
+
+    fn step(interpreter: &mut Interpreter, task: Task, sent: Object) -> Result<()> {
+        match task.take_kind() {
+            Some(TaskKind::Coroutine(mut coroutine)) => {
+                let res = coroutine.resume(interpreter, sent);
+                task.put_kind(TaskKind::Coroutine(coroutine));
+                match res? {
+                    Step::Await(value) => match Task::from_object(&value) {
+                        Some(awaited) if awaited.is_done() => {
+                            let result = awaited.get_result().unwrap_or(Object::Nil);
+                            interpreter.event_loop.ready.push_back((task, result));
+                        }
+                        Some(awaited) => {
+                            interpreter.event_loop.start(&awaited);
+                            awaited.add_waiter(task);
+                        }
+                        None => interpreter.event_loop.ready.push_back((task, value)),
+                    },
+                    Step::Yield(value) | Step::Complete(value) => {
+                        interpreter.event_loop.complete(&task, value)
+                    }
+                }
+            }
+            Some(TaskKind::Join(children)) => {
+                task.put_kind(TaskKind::Join(children.clone()));
+                for child in &children {
+                    interpreter.event_loop.start(child);
+                }
+                match children.iter().find(|child| !child.is_done()) {
+                    Some(child) => child.add_waiter(task),
+                    None => {
+                        let results = children
+                            .iter()
+                            .map(|child| child.get_result().unwrap_or(Object::Nil))
+                            .collect();
+                        interpreter
+                            .event_loop
+                            .complete(&task, Object::List(results));
+                    }
+                }
+            }
+            Some(kind) => task.put_kind(kind),
+            None => {}
+        }
+        Ok(())
+    }
+
+    fn fire(interpreter: &mut Interpreter, task: Task) -> Result<()> {
+        let value = match task.take_kind() {
+            Some(TaskKind::Timeout(callback, delay)) => {
+                task.put_kind(TaskKind::Timeout(callback.clone(), delay));
+                let value = callback.call(interpreter, vec![])?;
+                if let Some(spawned) = Task::from_object(&value) {
+                    interpreter.event_loop.start(&spawned);
+                }
+                value
+            }
+            Some(kind) => {
+                task.put_kind(kind);
+                Object::Nil
+            }
+            None => Object::Nil,
+        };
+        interpreter.event_loop.complete(&task, value);
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
+use crate::interpreter::Interpreter;
 use crate::interpreter::error::InterpreterError;
+use crate::interpreter::package::error::PackageError;
 use crate::interpreter::scanner::token::Token;
-use crate::interpreter::Interpreter;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Clone)]
@@ -34,6 +35,11 @@ impl From<ExporterError> for InterpreterError {
 #[derive(Debug, Clone)]
 pub enum ExporterErrorType {
     ExpectedPathStringAfterUse,
+    NameNotExported(String, String),
+    ImportCycle(String),
+    ModuleNotFound(String, Vec<String>),
+    ModuleReadFailed(String, String),
+    Package(PackageError),
 }
 
 impl Display for ExporterErrorType {
@@ -42,6 +48,22 @@ impl Display for ExporterErrorType {
             ExporterErrorType::ExpectedPathStringAfterUse => {
                 write!(f, "Expected path string after use")
             }
+            ExporterErrorType::NameNotExported(name, path) => {
+                write!(f, "Module '{}' doesn't export '{}'", path, name)
+            }
+            ExporterErrorType::ImportCycle(cycle) => write!(f, "Import cycle: {}", cycle),
+            ExporterErrorType::ModuleNotFound(path, attempted) => {
+                write!(
+                    f,
+                    "Module '{}' not found, tried: {}",
+                    path,
+                    attempted.join(", ")
+                )
+            }
+            ExporterErrorType::ModuleReadFailed(path, reason) => {
+                write!(f, "Failed to read module '{}': {}", path, reason)
+            }
+            ExporterErrorType::Package(err) => write!(f, "{}", err),
         }
     }
 }
@@ -0,0 +1,243 @@
+use crate::interpreter::error::Result;
+use crate::interpreter::exporter::error::ExporterErrorType;
+use crate::interpreter::package::error::PackageError;
+use crate::interpreter::package::{Lockfile, Package};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::fs::read_to_string;
+use std::path::{Component, Path, PathBuf};
+use std::time::SystemTime;
+
+/// Where the `Exporter` finds imported modules and their source. A module is
+/// identified by the path `resolve` returns, which is also the importer
+/// passed when resolving that module's own imports. Bundled `std/` modules
+/// never reach the loader.
+pub trait ModuleLoader {
+    /// Called with the script being run before any of its imports resolve.
+    fn start(&self, _script: &Path) -> Result<()> {
+        Ok(())
+    }
+
+    /// Path of the module `path` imported by the module at `importer`.
+    fn resolve(
+        &self,
+        path: &str,
+        importer: &Path,
+    ) -> std::result::Result<PathBuf, ExporterErrorType>;
+
+    /// Source of a module returned by `resolve`.
+    fn load(&self, module: &Path) -> std::result::Result<String, ExporterErrorType>;
+
+    /// When the module last changed, `None` if the loader can't tell.
+    fn modified(&self, _module: &Path) -> Option<SystemTime> {
+        None
+    }
+}
+
+/// Loads modules from disk. A module is looked up next to the importing
+/// file, in the dependency it names inside a package, in the search paths,
+/// `YUN_PATH` and the `modules/` directory next to the script being run.
+#[derive(Default)]
+pub struct FileLoader {
+    search_paths: Vec<PathBuf>,
+    script: RefCell<PathBuf>,
+    packages: RefCell<HashMap<PathBuf, Package>>,
+    /// Directory of the script's package with its lockfile.
+    lockfile: RefCell<Option<(PathBuf, Lockfile)>>,
+}
+
+impl FileLoader {
+    /// Directories searched for imported modules after the importing file's
+    /// own directory, before `YUN_PATH` and the project's `modules/`.
+    pub fn with_search_paths(mut self, search_paths: Vec<PathBuf>) -> Self {
+        self.search_paths = search_paths;
+        self
+    }
+
+    /// Package `file` belongs to, if a `yun.toml` is found above it.
+    fn package(&self, file: &Path) -> std::result::Result<Option<Package>, PackageError> {
+        let Some(manifest) = Package::find(&directory(file)) else {
+            return Ok(None);
+        };
+        if let Some(package) = self.packages.borrow().get(&manifest) {
+            return Ok(Some(package.clone()));
+        }
+        let package = Package::load(&manifest)?;
+        self.packages.borrow_mut().insert(manifest, package.clone());
+        Ok(Some(package))
+    }
+
+    /// Dependency named by the first segment of `path` in the importer's
+    /// package, with the rest of the path.
+    fn dependency<'a>(
+        &self,
+        path: &'a str,
+        importer: &Path,
+    ) -> std::result::Result<Option<(Package, &'a str)>, PackageError> {
+        let (name, rest) = path.split_once('/').unwrap_or((path, ""));
+        let Some(package) = self.package(importer)? else {
+            return Ok(None);
+        };
+        let Some(dependency) = package.dependency(name) else {
+            return Ok(None);
+        };
+        let dependency = dependency?;
+        if let Some((root, lockfile)) = self.lockfile.borrow().as_ref() {
+            lockfile.check(root, &dependency)?;
+        }
+        Ok(Some((dependency, rest)))
+    }
+
+    fn candidates(dir: &Path, path: &str) -> Vec<PathBuf> {
+        let dir = std::path::absolute(dir).unwrap_or(dir.to_path_buf());
+        vec![
+            dir.join(path.to_owned() + ".yun"),
+            dir.join(path).join("index.yun"),
+        ]
+    }
+}
+
+impl ModuleLoader for FileLoader {
+    /// Remembers the script and checks its package's `yun.lock`, bringing it
+    /// up to date. Code typed into the REPL doesn't touch the lockfile.
+    fn start(&self, script: &Path) -> Result<()> {
+        *self.script.borrow_mut() = script.to_path_buf();
+        if script.as_os_str().is_empty() {
+            return Ok(());
+        }
+        if let Some(package) = self.package(script)? {
+            let lockfile = package.lock()?;
+            *self.lockfile.borrow_mut() = Some((package.get_dir().to_path_buf(), lockfile));
+        }
+        Ok(())
+    }
+
+    /// In each directory `path.yun` is tried before the directory module
+    /// `path/index.yun`, a bare dependency name imports the dependency's
+    /// entry point.
+    fn resolve(
+        &self,
+        path: &str,
+        importer: &Path,
+    ) -> std::result::Result<PathBuf, ExporterErrorType> {
+        let mut files = FileLoader::candidates(&directory(importer), path);
+        match self
+            .dependency(path, importer)
+            .map_err(ExporterErrorType::Package)?
+        {
+            Some((package, "")) => files.push(package.entry()),
+            Some((package, rest)) => files.extend(FileLoader::candidates(package.get_dir(), rest)),
+            None => {}
+        }
+        let mut dirs = self.search_paths.clone();
+        if let Some(yun_path) = env::var_os("YUN_PATH") {
+            dirs.extend(env::split_paths(&yun_path));
+        }
+        dirs.push(directory(&self.script.borrow()).join("modules"));
+        for dir in dirs {
+            files.extend(FileLoader::candidates(&dir, path));
+        }
+
+        let mut attempted = vec![];
+        for file in files {
+            if file.is_file() {
+                return Ok(file.canonicalize().unwrap_or(file));
+            }
+            attempted.push(file.display().to_string());
+        }
+        Err(ExporterErrorType::ModuleNotFound(
+            path.to_string(),
+            attempted,
+        ))
+    }
+
+    fn load(&self, module: &Path) -> std::result::Result<String, ExporterErrorType> {
+        read_to_string(module).map_err(|err| {
+            ExporterErrorType::ModuleReadFailed(module.display().to_string(), err.to_string())
+        })
+    }
+
+    fn modified(&self, module: &Path) -> Option<SystemTime> {
+        fs::metadata(module).and_then(|meta| meta.modified()).ok()
+    }
+}
+
+/// Serves modules from sources held in memory, keyed by paths like
+/// `lib/math.yun`. Imports resolve relative to the importing module's key,
+/// trying `path.yun` before `path/index.yun` like on disk.
+#[derive(Default)]
+pub struct MemoryLoader {
+    modules: HashMap<PathBuf, String>,
+}
+
+impl MemoryLoader {
+    pub fn with_module(mut self, path: impl AsRef<Path>, source: &str) -> Self {
+        self.modules
+            .insert(normalize(path.as_ref()), source.to_string());
+        self
+    }
+}
+
+impl ModuleLoader for MemoryLoader {
+    fn resolve(
+        &self,
+        path: &str,
+        importer: &Path,
+    ) -> std::result::Result<PathBuf, ExporterErrorType> {
+        let dir = importer.parent().unwrap_or(Path::new(""));
+        let files = [
+            normalize(&dir.join(path.to_owned() + ".yun")),
+            normalize(&dir.join(path).join("index.yun")),
+        ];
+        match files.iter().find(|file| self.modules.contains_key(*file)) {
+            Some(file) => Ok(file.clone()),
+            None => Err(ExporterErrorType::ModuleNotFound(
+                path.to_string(),
+                files
+                    .iter()
+                    .map(|file| file.display().to_string())
+                    .collect(),
+            )),
+        }
+    }
+
+    fn load(&self, module: &Path) -> std::result::Result<String, ExporterErrorType> {
+        self.modules
+            .get(&normalize(module))
+            .cloned()
+            .ok_or_else(|| {
+                ExporterErrorType::ModuleReadFailed(
+                    module.display().to_string(),
+                    "no such module in memory".into(),
+                )
+            })
+    }
+}
+
+/// Absolute directory the imports of `file` are resolved against, the
+/// working directory for code typed into the REPL.
+pub fn directory(file: &Path) -> PathBuf {
+    let dir = match file.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    let dir = std::path::absolute(&dir).unwrap_or(dir);
+    dir.canonicalize().unwrap_or(dir)
+}
+
+/// `path` without `.` segments and with `..` applied.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normal = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normal.pop();
+            }
+            component => normal.push(component),
+        }
+    }
+    normal
+}
@@ -1,64 +1,192 @@
 use crate::b;
 use crate::interpreter::ast::expr::literal::Literal;
 use crate::interpreter::ast::stmt::export_stmt::Export;
-use crate::interpreter::ast::stmt::use_stmt::Use;
-use crate::interpreter::ast::stmt::Stmt;
+use crate::interpreter::ast::stmt::use_stmt::{Import, ModuleAst, Use};
+use crate::interpreter::ast::stmt::{Stmt, exported_names};
 use crate::interpreter::error::Result;
 use crate::interpreter::exporter::error::{ExporterError, ExporterErrorType};
+use crate::interpreter::exporter::loader::{ModuleLoader, directory};
 use crate::interpreter::object::Object;
 use crate::interpreter::parser::Parser;
 use crate::interpreter::scanner::Scanner;
-use std::fs::read_to_string;
-use std::path::PathBuf;
+use crate::interpreter::scanner::token::Token;
+use crate::interpreter::stdlib;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
 
 pub mod error;
+pub mod loader;
 
-pub struct Exporter<T> {
+/// Links every `use` to the module it imports. Modules are loaded once,
+/// resolve their own imports relative to their own directory, and an import
+/// of a module that is still loading is reported as a cycle. A module keeps
+/// its whole top level, only its `export`ed names are visible to importers.
+/// Where modules come from is up to the `ModuleLoader`.
+pub struct Exporter<T: 'static> {
     ast: Vec<Box<dyn Stmt<T>>>,
     path: PathBuf,
+    loader: Rc<dyn ModuleLoader>,
+    loading: Vec<PathBuf>,
+    loaded: HashMap<PathBuf, ModuleAst<T>>,
 }
 
 impl<T: 'static + Clone> Exporter<T> {
-    pub fn new(path: PathBuf, ast: Vec<Box<dyn Stmt<T>>>) -> Self {
-        Self { path, ast }
+    pub fn new(path: PathBuf, ast: Vec<Box<dyn Stmt<T>>>, loader: Rc<dyn ModuleLoader>) -> Self {
+        Self {
+            path,
+            ast,
+            loader,
+            loading: vec![],
+            loaded: HashMap::new(),
+        }
+    }
+
+    pub fn resolve(mut self) -> Result<Vec<Box<dyn Stmt<T>>>> {
+        let ast = std::mem::take(&mut self.ast);
+        let path = self.path.clone();
+        self.loading
+            .push(path.canonicalize().unwrap_or_else(|_| path.clone()));
+        self.loader.start(&path)?;
+        self.link(ast, &path)
+    }
+
+    /// Reads and links the module at `path` again, as loaded by `loader`,
+    /// reporting a failure to read it at the `use` token that imported it.
+    pub fn reload(
+        path: PathBuf,
+        token: &Token,
+        loader: Rc<dyn ModuleLoader>,
+    ) -> Result<ModuleAst<T>> {
+        let code = Exporter::<T>::read(loader.as_ref(), &path, token)?;
+        let tokens = Scanner::new(&code).scan_tokens()?;
+        let ast = Parser::new(tokens).parse()?;
+
+        let mut exporter = Exporter::new(path.clone(), vec![], loader);
+        exporter.loading.push(path.clone());
+        let body = exporter.link(ast, &path)?;
+        Ok(ModuleAst::new(path, body))
     }
 
-    pub fn resolve(self) -> Result<Vec<Box<dyn Stmt<T>>>> {
-        let mut ast = vec![];
-        for stmt in self.ast.clone() {
+    /// Source of the module at `path`, a loader failure is reported at `token`.
+    pub fn read(loader: &dyn ModuleLoader, path: &Path, token: &Token) -> Result<String> {
+        match stdlib::source(path) {
+            Some(code) => Ok(code.to_string()),
+            None => Ok(loader
+                .load(path)
+                .map_err(|err| ExporterError::new(token.clone(), err))?),
+        }
+    }
+
+    /// Names declared by the `export` statements of a module.
+    pub fn exports(ast: &[Box<dyn Stmt<T>>]) -> Vec<Token> {
+        exported_names(ast)
+    }
+
+    /// Attaches the imported module to every `use` of `ast`, which was read
+    /// from `path`, including the re-exporting `export use`.
+    fn link(&mut self, ast: Vec<Box<dyn Stmt<T>>>, path: &Path) -> Result<Vec<Box<dyn Stmt<T>>>> {
+        let mut linked: Vec<Box<dyn Stmt<T>>> = vec![];
+        for stmt in ast {
             if let Some(use_stmt) = stmt.downcast_ref::<Use<T>>() {
-                let (name, expr) = use_stmt.extract();
-                if let Some(expr) = expr.downcast_ref::<Literal>() {
-                    if let Some(Object::String(path)) = expr.get_value() {
-                        let path = self.path.parent().unwrap().join(path.to_owned() + ".yun");
-                        let code = read_to_string(path).unwrap();
-                        let tokens = Scanner::new(&code).scan_tokens()?;
-                        let exported_ast = self.sift(Parser::new(tokens).parse()?)?;
-                        for stmt in exported_ast {
-                            ast.push(stmt);
-                        }
-                    } else {
-                        return Err(ExporterError::new(
-                            name.clone(),
-                            ExporterErrorType::ExpectedPathStringAfterUse,
-                        )
-                        .into());
-                    }
-                }
+                linked.push(b!(self.link_use(use_stmt, path)?));
+            } else if let Some(export) = stmt.downcast_ref::<Export<T>>()
+                && let Some(use_stmt) = export.extract().1.downcast_ref::<Use<T>>()
+            {
+                let use_stmt = self.link_use(use_stmt, path)?;
+                linked.push(b!(Export::new(export.extract().0.clone(), b!(use_stmt))));
             } else {
-                ast.push(stmt);
+                linked.push(stmt);
             }
         }
-        Ok(ast)
+        Ok(linked)
     }
 
-    pub fn sift(&self, ast: Vec<Box<dyn Stmt<T>>>) -> Result<Vec<Box<dyn Stmt<T>>>> {
-        let mut sifted_ast: Vec<Box<dyn Stmt<T>>> = vec![];
-        for stmt in ast {
-            if let Some(export) = stmt.downcast_ref::<Export<T>>() {
-                sifted_ast.push(b!(export.clone()));
+    fn link_use(&mut self, use_stmt: &Use<T>, path: &Path) -> Result<Use<T>> {
+        let module = self.load(use_stmt, path)?;
+        if let Import::Names(names) = use_stmt.get_import() {
+            let exports = Exporter::exports(module.get_body());
+            if let Some((missing, _)) = names.iter().find(|(name, _)| {
+                !exports
+                    .iter()
+                    .any(|export| export.get_lexeme() == name.get_lexeme())
+            }) {
+                return Err(ExporterError::new(
+                    missing.clone(),
+                    ExporterErrorType::NameNotExported(
+                        missing.get_lexeme().to_string(),
+                        self.display(module.get_path()),
+                    ),
+                )
+                .into());
             }
         }
-        Ok(sifted_ast)
+        Ok(use_stmt.clone().with_module(module))
+    }
+
+    fn load(&mut self, use_stmt: &Use<T>, importer: &Path) -> Result<ModuleAst<T>> {
+        let (name, expr) = use_stmt.extract();
+        let Some(Object::String(path)) =
+            expr.downcast_ref::<Literal>().and_then(Literal::get_value)
+        else {
+            return Err(ExporterError::new(
+                name.clone(),
+                ExporterErrorType::ExpectedPathStringAfterUse,
+            )
+            .into());
+        };
+
+        let file = if path.starts_with(stdlib::PREFIX) {
+            PathBuf::from(path)
+        } else {
+            self.loader
+                .resolve(path, importer)
+                .map_err(|err| ExporterError::new(name.clone(), err))?
+        };
+        if stdlib::is_std(&file) && stdlib::source(&file).is_none() {
+            return Err(ExporterError::new(
+                name.clone(),
+                ExporterErrorType::ModuleNotFound(
+                    path.clone(),
+                    vec![format!("{} (bundled)", path)],
+                ),
+            )
+            .into());
+        }
+        if let Some(module) = self.loaded.get(&file) {
+            return Ok(module.clone());
+        }
+        if let Some(start) = self.loading.iter().position(|loading| *loading == file) {
+            let cycle = self.loading[start..]
+                .iter()
+                .chain([&file])
+                .map(|path| self.display(path))
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            return Err(
+                ExporterError::new(name.clone(), ExporterErrorType::ImportCycle(cycle)).into(),
+            );
+        }
+
+        let code = Exporter::<T>::read(self.loader.as_ref(), &file, name)?;
+        let tokens = Scanner::new(&code).scan_tokens()?;
+        let ast = Parser::new(tokens).parse()?;
+
+        self.loading.push(file.clone());
+        let body = self.link(ast, &file);
+        self.loading.pop();
+
+        let module = ModuleAst::new(file.clone(), body?);
+        self.loaded.insert(file, module.clone());
+        Ok(module)
+    }
+
+    /// Module path relative to the directory of the script being run.
+    fn display(&self, path: &Path) -> String {
+        let root = directory(&self.path);
+        path.strip_prefix(root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
     }
 }
@@ -1,51 +1,76 @@
 pub mod ast;
+pub mod coroutine;
 pub mod environment;
 pub mod error;
+pub mod event_loop;
 pub mod exporter;
 pub mod object;
+pub mod package;
 pub mod parser;
 pub mod scanner;
 pub mod shell;
+pub mod stdlib;
 
 use crate::interpreter::ast::expr::assignment::Assign;
+use crate::interpreter::ast::expr::await_expr::Await;
 use crate::interpreter::ast::expr::binary::Binary;
 use crate::interpreter::ast::expr::call::Call;
+use crate::interpreter::ast::expr::comprehension::{Clause, Comprehension};
 use crate::interpreter::ast::expr::get::Get;
 use crate::interpreter::ast::expr::grouping::Grouping;
+use crate::interpreter::ast::expr::index::Index;
+use crate::interpreter::ast::expr::list::List;
 use crate::interpreter::ast::expr::literal::Literal;
 use crate::interpreter::ast::expr::logical::Logical;
+use crate::interpreter::ast::expr::range::Range;
 use crate::interpreter::ast::expr::self_expr::SelfExpr;
 use crate::interpreter::ast::expr::set::Set;
+use crate::interpreter::ast::expr::superclass::Super;
 use crate::interpreter::ast::expr::unary::Unary;
 use crate::interpreter::ast::expr::variable::Variable;
+use crate::interpreter::ast::expr::yield_expr::Yield;
 use crate::interpreter::ast::expr::{CloneExpr, Expr, ExprVisitor};
+use crate::interpreter::ast::pattern::Pattern;
 use crate::interpreter::ast::stmt::block::Block;
 use crate::interpreter::ast::stmt::class::Class;
+use crate::interpreter::ast::stmt::destructure::Destructure;
+use crate::interpreter::ast::stmt::enum_stmt::Enum;
 use crate::interpreter::ast::stmt::export_stmt::Export;
+use crate::interpreter::ast::stmt::for_in::ForIn;
 use crate::interpreter::ast::stmt::fun_stmt::Fun;
 use crate::interpreter::ast::stmt::if_stmt::If;
 use crate::interpreter::ast::stmt::let_stmt::Let;
 use crate::interpreter::ast::stmt::print::Print;
 use crate::interpreter::ast::stmt::return_stmt::Return;
 use crate::interpreter::ast::stmt::stmt_expr::StmtExpr;
-use crate::interpreter::ast::stmt::use_stmt::Use;
+use crate::interpreter::ast::stmt::struct_stmt::Struct;
+use crate::interpreter::ast::stmt::use_stmt::{Import, ModuleAst, Use};
 use crate::interpreter::ast::stmt::while_stmt::While;
 use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
 use crate::interpreter::environment::Environment;
 use crate::interpreter::error::Result;
 use crate::interpreter::error::{InterpreterError, RuntimeError, RuntimeErrorType};
+use crate::interpreter::event_loop::EventLoop;
 use crate::interpreter::exporter::Exporter;
-use crate::interpreter::parser::resolver::Resolver;
+use crate::interpreter::exporter::loader::{FileLoader, ModuleLoader};
+use crate::interpreter::package::error::PackageError;
+use crate::interpreter::package::{MANIFEST, Package};
 use crate::interpreter::parser::Parser;
-use crate::interpreter::scanner::token::token_type::TokenType;
-use crate::interpreter::scanner::token::Token;
+use crate::interpreter::parser::resolver::Resolver;
 use crate::interpreter::scanner::Scanner;
+use crate::interpreter::scanner::token::Token;
+use crate::interpreter::scanner::token::token_type::TokenType;
 use crate::interpreter::shell::Shell;
 use crate::utils::next_id;
 use crate::{b, rc};
-use object::callable::Callable;
-use object::native_object::NativeObject;
 use object::Object;
+use object::callable::{CallFn, Callable};
+use object::generator::Generator;
+use object::instance::Instance;
+use object::iterator::{Done, NativeIterator};
+use object::module::Module;
+use object::native_object::NativeObject;
+use object::task::{Task, TaskKind};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::io::Write;
@@ -53,23 +78,40 @@ use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::process::exit;
 use std::rc::Rc;
-use std::time::Instant;
+use std::time::{Instant, SystemTime};
 use std::{fs, io};
-use crate::interpreter::ast::expr::list::List;
-use crate::interpreter::ast::expr::superclass::Super;
+
+/// A module executed by the interpreter, kept for `use` and `reload`.
+struct LoadedModule {
+    env: Rc<RefCell<Environment>>,
+    /// Export list shared with the module objects bound to it.
+    exports: Rc<RefCell<Vec<String>>>,
+    modified: Option<SystemTime>,
+    /// The `use` that first imported it, where reload failures are reported.
+    token: Token,
+    /// Scopes that imported one of its exports by name, with the export and
+    /// the name it is bound to.
+    bindings: Vec<(Rc<RefCell<Environment>>, Token, Token)>,
+}
 
 pub struct Interpreter {
     path: PathBuf,
     env: Option<Rc<RefCell<Environment>>>,
     globals: Option<Rc<RefCell<Environment>>>,
     locals: HashMap<u64, usize>,
+    resumed: Option<Object>,
+    event_loop: EventLoop,
+    modules: HashMap<PathBuf, LoadedModule>,
+    loader: Rc<dyn ModuleLoader>,
+    /// Instance of the decorated method being called, see `Object::bind`.
+    receiver: Option<Instance>,
 }
 
 impl Default for Interpreter {
     fn default() -> Self {
         let mut globals = Environment::default();
 
-        globals.define(
+        globals.define_builtin(
             "get",
             Some(Object::Callable(Callable::build(
                 next_id(),
@@ -78,8 +120,17 @@ impl Default for Interpreter {
                 rc!(|_, args| -> Result<Object> {
                     let list = args[0].clone();
                     let index = args[1].clone();
-                    if let (Object::List(list), Object::Number(number)) = (list, index) {
-                        return Ok(list.get(number as usize).unwrap_or(&Object::Nil).clone());
+                    if let (Object::List(list), Object::Number(number)) = (&list, &index) {
+                        return Ok(list.get(*number as usize).unwrap_or(&Object::Nil).clone());
+                    }
+                    if let (Object::List(list), Object::Range(range)) = (&list, &index) {
+                        return Ok(Object::List(
+                            (0..range.len())
+                                .filter_map(|i| range.nth(i))
+                                .filter(|i| *i >= 0.0)
+                                .filter_map(|i| list.get(i as usize).cloned())
+                                .collect(),
+                        ));
                     }
                     Ok(Object::Nil)
                 }),
@@ -89,7 +140,7 @@ impl Default for Interpreter {
             ))),
         );
 
-        globals.define(
+        globals.define_builtin(
             "clock",
             Some(Object::Callable(Callable::build(
                 next_id(),
@@ -109,7 +160,7 @@ impl Default for Interpreter {
             ))),
         );
 
-        globals.define(
+        globals.define_builtin(
             "panic",
             Some(Object::Callable(Callable::build(
                 next_id(),
@@ -122,7 +173,7 @@ impl Default for Interpreter {
             ))),
         );
 
-        globals.define(
+        globals.define_builtin(
             "string",
             Some(Object::Callable(Callable::build(
                 next_id(),
@@ -135,7 +186,7 @@ impl Default for Interpreter {
             ))),
         );
 
-        globals.define(
+        globals.define_builtin(
             "exit",
             Some(Object::Callable(Callable::build(
                 next_id(),
@@ -148,7 +199,7 @@ impl Default for Interpreter {
             ))),
         );
 
-        globals.define(
+        globals.define_builtin(
             "exitWithCode",
             Some(Object::Callable(Callable::build(
                 next_id(),
@@ -161,7 +212,7 @@ impl Default for Interpreter {
             ))),
         );
 
-        globals.define(
+        globals.define_builtin(
             "instant",
             Some(Object::Callable(Callable::build(
                 next_id(),
@@ -174,7 +225,7 @@ impl Default for Interpreter {
             ))),
         );
 
-        globals.define(
+        globals.define_builtin(
             "elapsed",
             Some(Object::Callable(Callable::build(
                 next_id(),
@@ -194,6 +245,312 @@ impl Default for Interpreter {
             ))),
         );
 
+        globals.define_builtin(
+            "type",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|_, args| Ok(Object::String(match args[0].inner() {
+                    Object::Class(_) => "class".into(),
+                    Object::Enum(_) => "enum".into(),
+                    obj => obj.get_type(),
+                }))),
+                rc!(|| 1),
+                rc!(|| "type".into()),
+                false,
+            ))),
+        );
+
+        globals.define_builtin(
+            "fields",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|_, args| {
+                    if let Object::Instance(instance) = args[0].inner() {
+                        return Ok(Object::List(
+                            instance
+                                .field_names()
+                                .into_iter()
+                                .map(Object::String)
+                                .collect(),
+                        ));
+                    }
+                    Ok(Object::Nil)
+                }),
+                rc!(|| 1),
+                rc!(|| "fields".into()),
+                false,
+            ))),
+        );
+
+        globals.define_builtin(
+            "field",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|_, args| {
+                    if let (Object::Instance(instance), Object::String(name)) =
+                        (args[0].inner(), args[1].inner())
+                    {
+                        return instance.get(
+                            &Token::builtin_void(TokenType::Identifier, name, None),
+                            None,
+                        );
+                    }
+                    Ok(Object::Nil)
+                }),
+                rc!(|| 2),
+                rc!(|| "field".into()),
+                false,
+            ))),
+        );
+
+        globals.define_builtin(
+            "methods",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|_, args| {
+                    let names = match args[0].inner() {
+                        Object::Class(class) => class.method_names(),
+                        Object::Instance(instance) => instance.get_class().method_names(),
+                        _ => return Ok(Object::Nil),
+                    };
+                    Ok(Object::List(
+                        names.into_iter().map(Object::String).collect(),
+                    ))
+                }),
+                rc!(|| 1),
+                rc!(|| "methods".into()),
+                false,
+            ))),
+        );
+
+        globals.define_builtin(
+            "classOf",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|_, args| match args[0].inner() {
+                    Object::Instance(instance) => Ok(Object::Rc(rc!(Object::Class(b!(instance
+                        .get_class()
+                        .clone()))))),
+                    Object::Variant(variant) => Ok(Object::Enum(variant.get_enum_rc())),
+                    _ => Ok(Object::Nil),
+                }),
+                rc!(|| 1),
+                rc!(|| "classOf".into()),
+                false,
+            ))),
+        );
+
+        globals.define_builtin(
+            "superclassOf",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|_, args| {
+                    if let Object::Class(class) = args[0].inner() {
+                        return Ok(class.get_superclass().unwrap_or(Object::Nil));
+                    }
+                    Ok(Object::Nil)
+                }),
+                rc!(|| 1),
+                rc!(|| "superclassOf".into()),
+                false,
+            ))),
+        );
+
+        globals.define_builtin(
+            "instanceOf",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|_, args| {
+                    Ok(Object::Bool(match (args[0].inner(), args[1].inner()) {
+                        (Object::Instance(instance), Object::Class(class)) => {
+                            instance.get_class().is_subclass_of(class)
+                        }
+                        (Object::Variant(variant), Object::Enum(enumeration)) => {
+                            variant.get_enum() == enumeration.deref()
+                        }
+                        _ => false,
+                    }))
+                }),
+                rc!(|| 2),
+                rc!(|| "instanceOf".into()),
+                false,
+            ))),
+        );
+
+        globals.define_builtin(
+            "len",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|_, args| Ok(args[0]
+                    .get_len()
+                    .map_or(Object::Nil, |len| Object::Number(len as f64)))),
+                rc!(|| 1),
+                rc!(|| "len".into()),
+                false,
+            ))),
+        );
+
+        globals.define_builtin(
+            "iter",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|interpreter, args| {
+                    let iterator = interpreter.iterator(args[0].clone())?;
+                    Ok(Object::NativeObject(NativeObject::new(b!(iterator))))
+                }),
+                rc!(|| 1),
+                rc!(|| "iter".into()),
+                false,
+            ))),
+        );
+
+        globals.define_builtin(
+            "next",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|interpreter, args| {
+                    let iterator = interpreter.iterator(args[0].clone())?;
+                    Ok(iterator
+                        .next(interpreter)?
+                        .unwrap_or(Object::NativeObject(NativeObject::new(b!(Done)))))
+                }),
+                rc!(|| 1),
+                rc!(|| "next".into()),
+                false,
+            ))),
+        );
+
+        globals.define_builtin(
+            "sleep",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|_, args| match args[0].inner() {
+                    Object::Number(ms) => Ok(Object::NativeObject(NativeObject::new(b!(
+                        Task::new("sleep", TaskKind::Sleep(*ms))
+                    )))),
+                    _ => Err(RuntimeErrorType::CantToNum(args[0].get_type()).into()),
+                }),
+                rc!(|| 1),
+                rc!(|| "sleep".into()),
+                false,
+            ))),
+        );
+
+        globals.define_builtin(
+            "setTimeout",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|interpreter, args| {
+                    let (Object::Callable(callback), Object::Number(ms)) =
+                        (args[0].inner(), args[1].inner())
+                    else {
+                        return Err(RuntimeErrorType::NotCallable.into());
+                    };
+                    if callback.arity() != 0 {
+                        return Err(RuntimeErrorType::ArityOfFuncNotEqSizeOfArgs.into());
+                    }
+                    let task = Task::new(
+                        &callback.get_string(),
+                        TaskKind::Timeout(callback.clone(), *ms),
+                    );
+                    interpreter.event_loop.start(&task);
+                    Ok(Object::NativeObject(NativeObject::new(b!(task))))
+                }),
+                rc!(|| 2),
+                rc!(|| "setTimeout".into()),
+                false,
+            ))),
+        );
+
+        globals.define_builtin(
+            "spawn",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|interpreter, args| match Task::from_object(&args[0]) {
+                    Some(task) => {
+                        interpreter.event_loop.start(&task);
+                        Ok(args[0].clone())
+                    }
+                    None => Err(RuntimeErrorType::NotATask(args[0].get_type()).into()),
+                }),
+                rc!(|| 1),
+                rc!(|| "spawn".into()),
+                false,
+            ))),
+        );
+
+        globals.define_builtin(
+            "join",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|_, args| {
+                    let Object::List(values) = args[0].inner() else {
+                        return Err(RuntimeErrorType::NotATask(args[0].get_type()).into());
+                    };
+                    let mut tasks = vec![];
+                    for value in values {
+                        match Task::from_object(value) {
+                            Some(task) => tasks.push(task),
+                            None => return Err(RuntimeErrorType::NotATask(value.get_type()).into()),
+                        }
+                    }
+                    Ok(Object::NativeObject(NativeObject::new(b!(Task::new(
+                        "join",
+                        TaskKind::Join(tasks)
+                    )))))
+                }),
+                rc!(|| 1),
+                rc!(|| "join".into()),
+                false,
+            ))),
+        );
+
+        globals.define_builtin(
+            "now",
+            Some(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(|interpreter, _| Ok(Object::Number(interpreter.event_loop.now()))),
+                rc!(|| 0),
+                rc!(|| "now".into()),
+                false,
+            ))),
+        );
+
+        globals.define_builtin(
+            "done",
+            Some(Object::NativeObject(NativeObject::new(b!(Done)))),
+        );
+
         let globals = Rc::new(RefCell::new(globals));
 
         Self {
@@ -201,11 +558,23 @@ impl Default for Interpreter {
             env: Some(globals.clone()),
             globals: Some(globals),
             locals: Default::default(),
+            resumed: None,
+            event_loop: EventLoop::default(),
+            modules: HashMap::new(),
+            loader: Rc::new(FileLoader::default()),
+            receiver: None,
         }
     }
 }
 
 impl Interpreter {
+    /// Loader used to find and read imported modules, a `FileLoader` unless
+    /// set.
+    pub fn with_loader(mut self, loader: impl ModuleLoader + 'static) -> Self {
+        self.loader = Rc::new(loader);
+        self
+    }
+
     pub fn run_shell(mut self) -> Result<()> {
         let mut shell = Shell::new();
         let shell_ref = shell.as_mut();
@@ -219,6 +588,23 @@ impl Interpreter {
 
             shell_ref.set_command(buf_line.trim().to_string());
 
+            if let Some(path) = shell_ref.get_reload() {
+                let res = match path {
+                    "" => self.reload_changed(),
+                    path => self.reload(Path::new(path)).map(|reloaded| {
+                        reloaded.then(|| PathBuf::from(path)).into_iter().collect()
+                    }),
+                };
+                match res {
+                    Ok(reloaded) if reloaded.is_empty() => println!("Nothing to reload"),
+                    Ok(reloaded) => reloaded
+                        .iter()
+                        .for_each(|path| println!("Reloaded {}", path.display())),
+                    Err(err) => println!("{}", err),
+                }
+                continue;
+            }
+
             match self.run(shell_ref.get_command()) {
                 Ok(res) => match res {
                     Object::Void => {}
@@ -230,7 +616,13 @@ impl Interpreter {
     }
 
     pub fn run_script(mut self, path: &Path) -> Result<()> {
-        self.path = path.to_path_buf();
+        self.path = match Interpreter::script(path) {
+            Ok(path) => path,
+            Err(err) => {
+                println!("{}", err);
+                exit(65)
+            }
+        };
         let code = fs::read_to_string(&self.path).unwrap();
         if let Err(err) = self.run(&code) {
             println!("{}", err);
@@ -239,12 +631,111 @@ impl Interpreter {
         Ok(())
     }
 
-    pub fn run_test(mut self, path: &PathBuf) -> Result<()> {
-        let code = fs::read_to_string(path).unwrap();
+    pub fn run_test(mut self, path: &Path) -> Result<()> {
+        self.path = Interpreter::script(path)?;
+        let code = fs::read_to_string(&self.path).unwrap();
         self.run(&code)?;
         Ok(())
     }
 
+    /// Runs `code` like a line typed into the REPL, keeping globals and loaded
+    /// modules between calls.
+    pub fn eval(&mut self, code: &str) -> Result<Object> {
+        self.run(code)
+    }
+
+    /// Re-runs the top level of the loaded module at `path` if the loader
+    /// reports it changed since it was loaded, or can't tell. The module's
+    /// environment is cleared first, so module objects and closures over it
+    /// see the new definitions, then names imported from it are rebound.
+    /// Returns whether the module was reloaded.
+    pub fn reload(&mut self, path: &Path) -> Result<bool> {
+        let path = path.canonicalize().unwrap_or(path.to_path_buf());
+        let Some(loaded) = self.modules.get(&path) else {
+            return Err(RuntimeErrorType::ModuleNotLoaded(path.display().to_string()).into());
+        };
+        let modified = self.loader.modified(&path);
+        if modified.is_some() && modified == loaded.modified {
+            return Ok(false);
+        }
+        let env = loaded.env.clone();
+        let token = loaded.token.clone();
+
+        let module = Exporter::reload(path.clone(), &token, self.loader.clone())?;
+        Resolver::new(self).resolve_module(&module)?;
+        let previous = std::mem::replace(
+            &mut *env.borrow_mut(),
+            Environment::new(self.globals.clone()),
+        );
+        if let Err(err) = self.execute_module(&module, env.clone()) {
+            *env.borrow_mut() = previous;
+            return Err(err);
+        }
+
+        if let Some(loaded) = self.modules.get_mut(&path) {
+            loaded.modified = modified;
+            *loaded.exports.borrow_mut() = Interpreter::export_names(&module);
+            for (scope, name, alias) in &loaded.bindings {
+                let Ok(value) = env.borrow().get(name) else {
+                    continue;
+                };
+                let constant = env.borrow().is_const(name.get_lexeme());
+                scope
+                    .borrow_mut()
+                    .rebind(alias.get_lexeme(), value, constant);
+            }
+        }
+        EventLoop::run(self, None)?;
+        Ok(true)
+    }
+
+    /// Reloads every loaded module whose modification time changed, returns
+    /// their paths.
+    pub fn reload_changed(&mut self) -> Result<Vec<PathBuf>> {
+        let mut changed = self
+            .modules
+            .iter()
+            .filter(|(path, loaded)| {
+                let modified = self.loader.modified(path);
+                modified.is_some() && modified != loaded.modified
+            })
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+        changed.sort();
+        for path in &changed {
+            self.reload(path)?;
+        }
+        Ok(changed)
+    }
+
+    /// Runs the module the loader serves at `path`, for hosts whose scripts
+    /// don't live on disk.
+    pub fn run_module(mut self, path: &Path) -> Result<()> {
+        let token = Token::builtin_void(TokenType::String, &path.display().to_string(), None);
+        let code = Exporter::<Result<Object>>::read(self.loader.as_ref(), path, &token)?;
+        self.path = path.to_path_buf();
+        self.run(&code)?;
+        Ok(())
+    }
+
+    /// File to run for `path`, the entry point of the package when it is a
+    /// directory with a `yun.toml`.
+    fn script(path: &Path) -> Result<PathBuf> {
+        if !path.is_dir() {
+            return Ok(path.to_path_buf());
+        }
+        let package = Package::load(&path.join(MANIFEST))?;
+        let entry = package.entry();
+        if !entry.is_file() {
+            return Err(PackageError::EntryNotFound(
+                package.get_name().to_string(),
+                entry.display().to_string(),
+            )
+            .into());
+        }
+        Ok(entry)
+    }
+
     fn run(&mut self, code: &str) -> Result<Object> {
         let mut scanner = Scanner::new(code);
         let tokens = scanner.scan_tokens()?;
@@ -253,11 +744,12 @@ impl Interpreter {
         let mut parser = Parser::new(tokens);
         let ast = parser.parse()?;
 
-        let ast = Exporter::new(self.path.clone(), ast).resolve()?;
+        let ast = Exporter::new(self.path.clone(), ast, self.loader.clone()).resolve()?;
 
         Resolver::new(self).resolve(ast.iter().map(AsRef::as_ref).collect())?;
 
         let res = self.interpret(ast)?;
+        EventLoop::run(self, None)?;
 
         Ok(res)
     }
@@ -312,6 +804,408 @@ impl Interpreter {
         }
     }
 
+    /// Environment of an imported module, executing its top level on the
+    /// first import only.
+    fn load_module(
+        &mut self,
+        keyword: &Token,
+        module: &ModuleAst<Result<Object>>,
+    ) -> Result<Rc<RefCell<Environment>>> {
+        if let Some(loaded) = self.modules.get(module.get_path()) {
+            return Ok(loaded.env.clone());
+        }
+        let modified = self.loader.modified(module.get_path());
+        let env = Rc::new(RefCell::new(Environment::new(self.globals.clone())));
+        self.execute_module(module, env.clone())?;
+        self.modules.insert(
+            module.get_path().to_path_buf(),
+            LoadedModule {
+                env: env.clone(),
+                exports: Rc::new(RefCell::new(Interpreter::export_names(module))),
+                modified,
+                token: keyword.clone(),
+                bindings: vec![],
+            },
+        );
+        Ok(env)
+    }
+
+    fn export_names(module: &ModuleAst<Result<Object>>) -> Vec<String> {
+        Exporter::exports(module.get_body())
+            .iter()
+            .map(|name| name.get_lexeme().to_string())
+            .collect()
+    }
+
+    fn execute_module(
+        &mut self,
+        module: &ModuleAst<Result<Object>>,
+        env: Rc<RefCell<Environment>>,
+    ) -> Result<()> {
+        for (name, native) in stdlib::natives(module.get_path()) {
+            env.borrow_mut().define(name, Some(native))?;
+        }
+        let body = module.get_body();
+        self.execute_block(body.iter().map(AsRef::as_ref).collect(), env)?;
+        Ok(())
+    }
+
+    fn evaluate_decorators(&mut self, stmt: &Fun<Result<Object>>) -> Result<Vec<Object>> {
+        let mut decorators = Vec::with_capacity(stmt.get_decorators().len());
+        for decorator in stmt.get_decorators() {
+            decorators.push(self.evaluate(decorator.as_ref())?);
+        }
+        Ok(decorators)
+    }
+
+    /// Applies decorators to `func`, the one nearest to the declaration first,
+    /// so `@a @b fun f() {}` binds `a(b(f))`.
+    fn decorate(&mut self, decorators: &[Object], func: Object, name: Token) -> Result<Object> {
+        let mut func = func;
+        for decorator in decorators.iter().rev() {
+            let decorator = match decorator.clone_into_rc() {
+                Object::Class(class) => Object::Callable((*class).into()),
+                decorator => decorator,
+            };
+            let Object::Callable(decorator) = decorator else {
+                return Err(RuntimeError::new(name, RuntimeErrorType::NotCallable).into());
+            };
+            let args = Interpreter::arrange_args(&decorator, name.clone(), vec![func], vec![])?;
+            func = Interpreter::handle_runtime_error(name.clone(), decorator.call(self, args))?;
+        }
+        Ok(func)
+    }
+
+    /// Places named arguments into their parameter slots and fills the missing
+    /// ones from the callable's defaults.
+    fn arrange_args(
+        callable: &Callable,
+        token: Token,
+        args: Vec<Object>,
+        named_args: Vec<(Token, Object)>,
+    ) -> Result<Vec<Object>> {
+        let arity = callable.arity();
+        if named_args.is_empty() && args.len() == arity {
+            return Ok(args);
+        }
+        if args.len() > arity {
+            return Err(
+                RuntimeError::new(token, RuntimeErrorType::ArityOfFuncNotEqSizeOfArgs).into(),
+            );
+        }
+
+        let mut slots: Vec<Option<Object>> = args.into_iter().map(Some).collect();
+        slots.resize(arity, None);
+
+        if !named_args.is_empty() {
+            let params = match callable.get_params() {
+                Some(params) => params,
+                None => {
+                    return Err(
+                        RuntimeError::new(token, RuntimeErrorType::NamedArgsNotSupported).into(),
+                    );
+                }
+            };
+            for (name, value) in named_args {
+                let index = match params.iter().position(|param| param == name.get_lexeme()) {
+                    Some(index) => index,
+                    None => {
+                        let lexeme = name.get_lexeme().to_string();
+                        return Err(RuntimeError::new(
+                            name,
+                            RuntimeErrorType::UnexpectedNamedArg(lexeme),
+                        )
+                        .into());
+                    }
+                };
+                if slots[index].is_some() {
+                    let lexeme = name.get_lexeme().to_string();
+                    return Err(
+                        RuntimeError::new(name, RuntimeErrorType::DuplicateArg(lexeme)).into(),
+                    );
+                }
+                slots[index] = Some(value);
+            }
+        }
+
+        let mut arranged = Vec::with_capacity(arity);
+        for (index, slot) in slots.into_iter().enumerate() {
+            match slot.or_else(|| callable.get_default(index)) {
+                Some(value) => arranged.push(value),
+                None => {
+                    return Err(RuntimeError::new(
+                        token,
+                        RuntimeErrorType::ArityOfFuncNotEqSizeOfArgs,
+                    )
+                    .into());
+                }
+            }
+        }
+        Ok(arranged)
+    }
+
+    /// Class of the `self` visible from the code being executed, used to check
+    /// access to the private member `name`.
+    fn accessor_class(&self, name: &Token) -> Option<object::class::Class> {
+        if !Instance::is_private_member(name.get_lexeme()) {
+            return None;
+        }
+        let env = self.env.as_ref()?;
+        match env
+            .borrow()
+            .get(&Token::builtin_void(TokenType::Slf, "self", None))
+        {
+            Ok(Object::Instance(instance)) => Some(instance.get_class().clone()),
+            _ => None,
+        }
+    }
+
+    /// Runs the remaining comprehension clauses, giving every `for` clause
+    /// its own environment per element just like a `for-in` loop.
+    fn comprehend(
+        &mut self,
+        comprehension: &Comprehension<Result<Object>>,
+        clauses: &[Clause<Result<Object>>],
+        values: &mut Vec<Object>,
+    ) -> Result<()> {
+        let Some((clause, rest)) = clauses.split_first() else {
+            values.push(self.evaluate(comprehension.get_element())?);
+            return Ok(());
+        };
+
+        match clause {
+            Clause::If(condition) => {
+                if self.evaluate(condition.as_ref())? == Object::Bool(true) {
+                    self.comprehend(comprehension, rest, values)?;
+                }
+            }
+            Clause::For(keyword, pattern, iterable) => {
+                let iterable = self.evaluate(iterable.as_ref())?;
+                let iterator = match self.iterator(iterable) {
+                    Ok(iterator) => iterator,
+                    Err(err) => {
+                        return Interpreter::handle_runtime_error(keyword.clone(), Err(err))
+                            .map(|_| ());
+                    }
+                };
+                loop {
+                    let value = match iterator.next(self) {
+                        Ok(Some(value)) => value,
+                        Ok(None) => break,
+                        Err(err) => {
+                            return Interpreter::handle_runtime_error(keyword.clone(), Err(err))
+                                .map(|_| ());
+                        }
+                    };
+
+                    let env = Environment::new(self.env.clone());
+                    let previous = self.env.replace(Rc::new(RefCell::new(env)));
+                    let res = self
+                        .bind_pattern(pattern, value, false)
+                        .and_then(|_| self.comprehend(comprehension, rest, values));
+                    self.env = previous;
+                    res?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Turns `obj` into an iterator: lists and strings are walked element by
+    /// element, instances follow the `iter()`/`next()` protocol where `next()`
+    /// returning `done` ends the iteration.
+    pub fn iterator(&mut self, obj: Object) -> Result<NativeIterator> {
+        match obj.inner() {
+            Object::List(values) => Ok(NativeIterator::from_values(values.clone())),
+            Object::Range(range) => Ok(range.iter()),
+            Object::String(str) => Ok(NativeIterator::from_values(
+                str.chars()
+                    .map(|ch| Object::String(ch.to_string()))
+                    .collect(),
+            )),
+            Object::NativeObject(native) => {
+                let native = native.clone().extract();
+                if let Some(generator) = native.downcast_ref::<Generator>() {
+                    return Ok(generator.iter());
+                }
+                match native.downcast_ref::<NativeIterator>() {
+                    Some(iterator) => Ok(iterator.clone()),
+                    None => Err(RuntimeErrorType::NotIterable(obj.get_type()).into()),
+                }
+            }
+            Object::Instance(instance) => {
+                if instance.get_class().find_method("iter").is_none() {
+                    return Interpreter::next_iterator(instance);
+                }
+                let iter = Token::builtin_void(TokenType::Identifier, "iter", None);
+                let iterator = match instance.get(&iter, Some(instance.get_class()))? {
+                    Object::Callable(callable) => callable.call(self, vec![])?,
+                    _ => return Err(RuntimeErrorType::NotIterable(obj.get_type()).into()),
+                };
+                match iterator.inner() {
+                    Object::Instance(iterator) => Interpreter::next_iterator(iterator),
+                    _ => self.iterator(iterator),
+                }
+            }
+            _ => Err(RuntimeErrorType::NotIterable(obj.get_type()).into()),
+        }
+    }
+
+    fn range_property(range: &object::range::Range, name: &Token) -> Result<Object> {
+        match name.get_lexeme() {
+            "start" => Ok(Object::Number(range.get_start())),
+            "end" => Ok(Object::Number(range.get_end())),
+            "step" => {
+                let range = range.clone();
+                Ok(Object::Callable(Callable::build(
+                    next_id(),
+                    None,
+                    None,
+                    rc!(move |_, args| match args[0].inner() {
+                        Object::Number(step) => Ok(Object::Range(range.with_step(*step)?)),
+                        _ => Err(RuntimeErrorType::CantToNum(args[0].get_type()).into()),
+                    }),
+                    rc!(|| 1),
+                    rc!(|| "step".into()),
+                    false,
+                )))
+            }
+            _ => Err(RuntimeError::new(
+                name.clone(),
+                RuntimeErrorType::UndefinedProperty(name.get_lexeme().to_string()),
+            )
+            .into()),
+        }
+    }
+
+    fn generator_property(generator: &Generator, name: &Token) -> Result<Object> {
+        let method = |arity: usize, call: CallFn| {
+            let name = name.get_lexeme().to_string();
+            Ok(Object::Callable(Callable::build(
+                next_id(),
+                None,
+                None,
+                call,
+                rc!(move || arity),
+                rc!(move || name.clone()),
+                false,
+            )))
+        };
+        let generator = generator.clone();
+        match name.get_lexeme() {
+            "next" => method(
+                0,
+                rc!(move |interpreter, _| generator.send(interpreter, Object::Nil)),
+            ),
+            "send" => method(
+                1,
+                rc!(move |interpreter, args| generator.send(interpreter, args[0].clone())),
+            ),
+            "close" => method(1, rc!(move |_, args| generator.finish(args[0].clone()))),
+            "done" => Ok(Object::Bool(generator.is_done())),
+            _ => Err(RuntimeError::new(
+                name.clone(),
+                RuntimeErrorType::UndefinedProperty(name.get_lexeme().to_string()),
+            )
+            .into()),
+        }
+    }
+
+    fn next_iterator(instance: &Instance) -> Result<NativeIterator> {
+        if instance.get_class().find_method("next").is_none() {
+            return Err(RuntimeErrorType::NotIterable(
+                Object::Instance(instance.clone()).get_type(),
+            )
+            .into());
+        }
+        let instance = instance.clone();
+        let next = Token::builtin_void(TokenType::Identifier, "next", None);
+        Ok(NativeIterator::new(move |interpreter| {
+            match instance.get(&next, Some(instance.get_class()))? {
+                Object::Callable(callable) => match callable.call(interpreter, vec![])? {
+                    value if Done::is(&value) => Ok(None),
+                    value => Ok(Some(value)),
+                },
+                _ => Ok(None),
+            }
+        }))
+    }
+
+    /// Binds the names of `pattern` to the matching parts of `value` in the
+    /// current environment.
+    fn bind_pattern(&mut self, pattern: &Pattern, value: Object, constant: bool) -> Result<()> {
+        let env = match self.env.clone() {
+            Some(env) => env,
+            None => {
+                return Err(RuntimeError::new(
+                    pattern.get_token(),
+                    RuntimeErrorType::BugEnvironmentNotInit,
+                )
+                .into());
+            }
+        };
+
+        match pattern {
+            Pattern::Name(name) if constant => {
+                env.borrow_mut().define_const(name.get_lexeme(), value)
+            }
+            Pattern::Name(name) => Interpreter::define(&env, name, Some(value))?,
+            Pattern::List(token, items, rest) => {
+                let values = match value.inner() {
+                    Object::List(values) => values.clone(),
+                    obj => {
+                        return Err(RuntimeError::new(
+                            token.clone(),
+                            RuntimeErrorType::CannotDestructure(obj.get_type(), "list".into()),
+                        )
+                        .into());
+                    }
+                };
+                if values.len() < items.len() || (rest.is_none() && values.len() != items.len()) {
+                    return Err(RuntimeError::new(
+                        token.clone(),
+                        RuntimeErrorType::DestructureLengthMismatch(items.len(), values.len()),
+                    )
+                    .into());
+                }
+                let mut values = values.into_iter();
+                for item in items {
+                    self.bind_pattern(item, values.next().unwrap(), constant)?;
+                }
+                if let Some(rest) = rest {
+                    let rest = Pattern::Name(rest.clone());
+                    self.bind_pattern(&rest, Object::List(values.collect()), constant)?;
+                }
+            }
+            Pattern::Fields(token, fields) => {
+                for field in fields {
+                    let value = match value.inner() {
+                        Object::Instance(instance) => {
+                            instance.get(field, self.accessor_class(field).as_ref())?
+                        }
+                        Object::Variant(variant) => variant.get(field)?,
+                        obj => {
+                            return Err(RuntimeError::new(
+                                token.clone(),
+                                RuntimeErrorType::CannotDestructure(obj.get_type(), "field".into()),
+                            )
+                            .into());
+                        }
+                    };
+                    self.bind_pattern(&Pattern::Name(field.clone()), value, constant)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Defines `name` in `env`, a redeclared constant is reported at `name`.
+    fn define(env: &Rc<RefCell<Environment>>, name: &Token, value: Option<Object>) -> Result<()> {
+        let res = env.borrow_mut().define(name.get_lexeme(), value);
+        Interpreter::handle_runtime_error(name.clone(), res.map(|_| Object::Nil))?;
+        Ok(())
+    }
+
     #[inline]
     fn handle_runtime_error(token: Token, res: Result<Object>) -> Result<Object> {
         if let Err(err) = res {
@@ -393,6 +1287,7 @@ impl ExprVisitor<Result<Object>> for Interpreter {
             TokenType::Minus => left - right,
             TokenType::Star => left * right,
             TokenType::Slash => left / right,
+            TokenType::In => right.contains(&left).map(Object::Bool),
             _ => Err(RuntimeError::new(
                 binary.get_token(),
                 RuntimeErrorType::UnsupportedBinaryOperator(binary.get_op_lexeme().into()),
@@ -462,6 +1357,10 @@ impl ExprVisitor<Result<Object>> for Interpreter {
         for arg in call_.get_args() {
             args.push(self.evaluate(arg)?);
         }
+        let mut named_args = Vec::new();
+        for (name, arg) in call_.get_named_args() {
+            named_args.push((name.clone(), self.evaluate(arg)?));
+        }
 
         let callable = match callable.clone_into_rc() {
             Object::Class(class) => Object::Callable((*class).into()),
@@ -470,13 +1369,8 @@ impl ExprVisitor<Result<Object>> for Interpreter {
 
         match callable {
             Object::Callable(callable) => {
-                if args.len() != callable.arity() {
-                    return Err(RuntimeError::new(
-                        call_.get_token(),
-                        RuntimeErrorType::ArityOfFuncNotEqSizeOfArgs,
-                    )
-                    .into());
-                }
+                let args =
+                    Interpreter::arrange_args(&callable, call_.get_token(), args, named_args)?;
                 Interpreter::handle_runtime_error(call_.get_token(), callable.call(self, args))
             }
             _ => Err(RuntimeError::new(call_.get_token(), RuntimeErrorType::NotCallable).into()),
@@ -486,8 +1380,26 @@ impl ExprVisitor<Result<Object>> for Interpreter {
     fn visit_get(&mut self, get: &Get<Result<Object>>) -> Result<Object> {
         let (name, obj) = get.extract();
         let obj = self.evaluate(obj)?;
-        if let Object::Instance(instance) = obj {
-            return instance.get(name);
+        match obj.inner() {
+            Object::Instance(instance) => {
+                return instance.get(name, self.accessor_class(name).as_ref());
+            }
+            Object::Enum(enumeration) => return object::enumeration::Enum::get(enumeration, name),
+            Object::Variant(variant) => return variant.get(name),
+            Object::Range(range) => return Interpreter::range_property(range, name),
+            Object::NativeObject(native) => {
+                let native = native.clone().extract();
+                if let Some(generator) = native.downcast_ref::<Generator>() {
+                    return Interpreter::generator_property(generator, name);
+                }
+                if let (Some(task), "done") = (native.downcast_ref::<Task>(), name.get_lexeme()) {
+                    return Ok(Object::Bool(task.is_done()));
+                }
+                if let Some(module) = native.downcast_ref::<Module>() {
+                    return module.get(name);
+                }
+            }
+            _ => {}
         }
         Err(RuntimeError::new(name.clone(), RuntimeErrorType::OnlyInstancesHaveProperties).into())
     }
@@ -497,7 +1409,7 @@ impl ExprVisitor<Result<Object>> for Interpreter {
         let obj = self.evaluate(obj)?;
         if let Object::Instance(instance) = obj {
             let value = self.evaluate(value)?;
-            instance.set(name, value.clone());
+            instance.set(name, value.clone(), self.accessor_class(name).as_ref())?;
             return Ok(value);
         }
         Err(RuntimeError::new(name.clone(), RuntimeErrorType::OnlyInstancesHaveProperties).into())
@@ -509,15 +1421,20 @@ impl ExprVisitor<Result<Object>> for Interpreter {
 
     fn visit_super(&mut self, super_val: &Super) -> Result<Object> {
         let (keyword, method_name) = super_val.extract();
-        let distance = self.locals.get(&<Super as Expr<Result<Object>>>::id(super_val)).unwrap();
+        let distance = self
+            .locals
+            .get(&<Super as Expr<Result<Object>>>::id(super_val))
+            .unwrap();
         let superclass = Environment::get_at(self.env.clone(), *distance, &keyword)?;
-        let instance = Environment::get_at(self.env.clone(), 1, &Token::builtin_void(TokenType::Slf, "self", None))?;
+        let instance = Environment::get_at(
+            self.env.clone(),
+            1,
+            &Token::builtin_void(TokenType::Slf, "self", None),
+        )?;
 
         let method = match superclass.inner() {
-            Object::Class(class) => {
-                class.find_method(method_name.get_lexeme())
-            }
-            _ => panic!("Interpreter bug!")
+            Object::Class(class) => class.find_method(method_name.get_lexeme()),
+            _ => panic!("Interpreter bug!"),
         };
 
         if let Some(method) = method {
@@ -526,7 +1443,11 @@ impl ExprVisitor<Result<Object>> for Interpreter {
             }
         }
 
-        Err(RuntimeError::new(method_name.clone(), RuntimeErrorType::UndefinedProperty(method_name.get_lexeme().into())).into())
+        Err(RuntimeError::new(
+            method_name.clone(),
+            RuntimeErrorType::UndefinedProperty(method_name.get_lexeme().into()),
+        )
+        .into())
     }
 
     fn visit_list(&mut self, list: &List<Result<Object>>) -> Result<Object> {
@@ -536,6 +1457,88 @@ impl ExprVisitor<Result<Object>> for Interpreter {
         }
         Ok(Object::List(values))
     }
+
+    fn visit_range(&mut self, range: &Range<Result<Object>>) -> Result<Object> {
+        let (start, operator, end) = range.extract();
+        let start = self.evaluate(start)?;
+        let end = self.evaluate(end)?;
+        match (start.inner(), end.inner()) {
+            (Object::Number(start), Object::Number(end)) => Ok(Object::Range(
+                object::range::Range::new(*start, *end, range.is_inclusive()),
+            )),
+            _ => Err(RuntimeError::new(
+                operator.clone(),
+                RuntimeErrorType::RangeBoundsMustBeNumbers(start.get_type(), end.get_type()),
+            )
+            .into()),
+        }
+    }
+
+    fn visit_comprehension(
+        &mut self,
+        comprehension: &Comprehension<Result<Object>>,
+    ) -> Result<Object> {
+        let mut values = vec![];
+        self.comprehend(comprehension, comprehension.get_clauses(), &mut values)?;
+        Ok(Object::List(values))
+    }
+
+    fn visit_yield(&mut self, yield_expr: &Yield<Result<Object>>) -> Result<Object> {
+        match self.resumed.take() {
+            Some(value) => Ok(value),
+            None => Err(RuntimeError::new(
+                yield_expr.get_keyword().clone(),
+                RuntimeErrorType::BugYieldOutsideGenerator,
+            )
+            .into()),
+        }
+    }
+
+    fn visit_await(&mut self, await_expr: &Await<Result<Object>>) -> Result<Object> {
+        if let Some(value) = self.resumed.take() {
+            return Ok(value);
+        }
+        let value = self.evaluate(await_expr.get_value())?;
+        match Task::from_object(&value) {
+            Some(task) => Interpreter::handle_runtime_error(
+                await_expr.get_keyword().clone(),
+                EventLoop::block_on(self, &task),
+            ),
+            None => Ok(value),
+        }
+    }
+
+    fn visit_index(&mut self, index: &Index<Result<Object>>) -> Result<Object> {
+        let object = self.evaluate(index.get_object())?;
+        let bracket = index.get_bracket();
+        let [start, end, step] = index.get_bounds();
+        if !index.is_slice() {
+            let start = match start {
+                Some(start) => self.evaluate(start)?,
+                None => Object::Nil,
+            };
+            return Self::handle_runtime_error(bracket.clone(), object.index(&start));
+        }
+
+        let mut bounds = [None; 3];
+        for (bound, expr) in bounds.iter_mut().zip([start, end, step]) {
+            if let Some(expr) = expr {
+                *bound = match self.evaluate(expr)?.inner() {
+                    Object::Nil => None,
+                    Object::Number(n) => Some(*n),
+                    other => {
+                        return Err(RuntimeError::new(
+                            bracket.clone(),
+                            RuntimeErrorType::IndexMustBeNumber(other.get_type()),
+                        )
+                        .into());
+                    }
+                };
+            }
+        }
+        let [start, end, step] = bounds;
+        Self::handle_runtime_error(bracket.clone(), object.slice(start, end, step))
+    }
 }
 
 impl StmtVisitor<Result<Object>> for Interpreter {
@@ -562,10 +1565,11 @@ impl StmtVisitor<Result<Object>> for Interpreter {
                         )
                         .into());
                     }
-                    Some(env) => {
+                    Some(env) if stmt.is_const() => {
                         env.borrow_mut()
-                            .define(stmt.get_ident().get_lexeme(), Some(value));
+                            .define_const(stmt.get_ident().get_lexeme(), value);
                     }
+                    Some(env) => Interpreter::define(env, &stmt.get_ident(), Some(value))?,
                 }
             }
             None => match &self.env {
@@ -576,9 +1580,7 @@ impl StmtVisitor<Result<Object>> for Interpreter {
                     )
                     .into());
                 }
-                Some(env) => {
-                    env.borrow_mut().define(stmt.get_ident().get_lexeme(), None);
-                }
+                Some(env) => Interpreter::define(env, &stmt.get_ident(), None)?,
             },
         }
         Ok(Object::Nil)
@@ -615,7 +1617,9 @@ impl StmtVisitor<Result<Object>> for Interpreter {
 
     fn visit_fun(&mut self, stmt: &Fun<Result<Object>>) -> Result<Object> {
         let name = stmt.get_name();
+        let decorators = self.evaluate_decorators(stmt)?;
         let func = Object::function(stmt.clone(), self.env.clone(), false);
+        let func = self.decorate(&decorators, func, name.clone())?;
 
         match &self.env {
             None => {
@@ -623,9 +1627,7 @@ impl StmtVisitor<Result<Object>> for Interpreter {
                     RuntimeError::new(name, RuntimeErrorType::BugEnvironmentNotInit).into(),
                 );
             }
-            Some(env) => {
-                env.borrow_mut().define(name.get_lexeme(), Some(func));
-            }
+            Some(env) => Interpreter::define(env, &name, Some(func))?,
         }
 
         Ok(Object::Nil)
@@ -643,10 +1645,8 @@ impl StmtVisitor<Result<Object>> for Interpreter {
     fn visit_class(&mut self, class: &Class<Result<Object>>) -> Result<Object> {
         let (name, methods, superclass) = class.extract();
         if let Some(env) = self.env.clone() {
-            
-
             let superclass = if let Some(superclass) = superclass {
-                if let Object::Rc(rc) =  self.evaluate(superclass)? {
+                if let Object::Rc(rc) = self.evaluate(superclass)? {
                     if let Object::Class(_) = rc.deref() {
                         Some(Object::Rc(rc))
                     } else {
@@ -654,40 +1654,63 @@ impl StmtVisitor<Result<Object>> for Interpreter {
                             superclass.get_token(),
                             RuntimeErrorType::SuperclassMustBeClass,
                         )
-                            .into());
+                        .into());
                     }
                 } else {
                     return Err(RuntimeError::new(
                         superclass.get_token(),
                         RuntimeErrorType::SuperclassMustBeClass,
                     )
-                        .into());
+                    .into());
                 }
-                
             } else {
                 None
             };
-            
+
             if let Some(superclass) = superclass.clone() {
                 self.env = Some(Rc::new(RefCell::new(Environment::new(self.env.clone()))));
-                self.env.clone().unwrap().borrow_mut().define("super", Some(superclass));
+                self.env
+                    .clone()
+                    .unwrap()
+                    .borrow_mut()
+                    .define("super", Some(superclass))?;
             }
 
-            env.borrow_mut().define(name.get_lexeme(), None);
-            
+            Interpreter::define(&env, name, None)?;
+
             let mut methods_ = HashMap::with_capacity(methods.len());
 
             for method in methods {
                 let name = method.get_name();
-                let func = Object::function(
+                let mut func = Object::function(
                     method.clone(),
                     self.env.clone(),
                     method.get_name().get_lexeme().eq("init"),
                 );
+                let decorators = self.evaluate_decorators(method)?;
+                if !decorators.is_empty() {
+                    let unbound = object::class::Class::unbound_method(func, name.clone());
+                    func = match self.decorate(&decorators, unbound, name.clone())? {
+                        Object::Callable(decorated) => {
+                            object::class::Class::decorated_method(decorated)
+                        }
+                        _ => {
+                            return Err(RuntimeError::new(
+                                name.clone(),
+                                RuntimeErrorType::NotCallable,
+                            )
+                            .into());
+                        }
+                    };
+                }
                 methods_.insert(name.get_lexeme().to_string(), func);
             }
 
-            let class = Object::class(name.get_lexeme(), methods_, superclass.clone());
+            let class = Object::Class(b!(object::class::Class::new(
+                name.get_lexeme().to_string(),
+                methods_,
+                superclass.clone()
+            )));
 
             if superclass.is_some() {
                 self.env = self.env.clone().unwrap().borrow().get_enclosing();
@@ -705,7 +1728,141 @@ impl StmtVisitor<Result<Object>> for Interpreter {
         Ok(Object::Nil)
     }
 
-    fn visit_use(&mut self, _stmt: &Use<Result<Object>>) -> Result<Object> {
+    fn visit_use(&mut self, stmt: &Use<Result<Object>>) -> Result<Object> {
+        let (keyword, _) = stmt.extract();
+        let Some(module) = stmt.get_module() else {
+            return Ok(Object::Nil);
+        };
+        let Some(scope) = self.env.clone() else {
+            return Err(RuntimeError::new(
+                keyword.clone(),
+                RuntimeErrorType::BugEnvironmentNotInit,
+            )
+            .into());
+        };
+
+        let env = self.load_module(keyword, module)?;
+        let exports = Exporter::exports(module.get_body());
+        let names = match stmt.get_import() {
+            Import::All => exports
+                .into_iter()
+                .map(|name| (name.clone(), name))
+                .collect(),
+            Import::Names(names) => names.clone(),
+            Import::Module(alias) => {
+                let exports = match self.modules.get(module.get_path()) {
+                    Some(loaded) => loaded.exports.clone(),
+                    None => Rc::new(RefCell::new(Interpreter::export_names(module))),
+                };
+                let module = Module::new(alias.get_lexeme(), env, exports);
+                Interpreter::define(
+                    &scope,
+                    alias,
+                    Some(Object::NativeObject(NativeObject::new(Box::new(module)))),
+                )?;
+                return Ok(Object::Nil);
+            }
+        };
+
+        for (name, alias) in names {
+            let value = env.borrow().get(&name)?;
+            if env.borrow().is_const(name.get_lexeme()) {
+                scope.borrow_mut().define_const(alias.get_lexeme(), value);
+            } else {
+                Interpreter::define(&scope, &alias, Some(value))?;
+            }
+            if let Some(loaded) = self.modules.get_mut(module.get_path()) {
+                loaded.bindings.retain(|(bound, _, bound_alias)| {
+                    !Rc::ptr_eq(bound, &scope) || bound_alias.get_lexeme() != alias.get_lexeme()
+                });
+                loaded.bindings.push((scope.clone(), name, alias));
+            }
+        }
+        Ok(Object::Nil)
+    }
+
+    fn visit_struct(&mut self, stmt: &Struct<Result<Object>>) -> Result<Object> {
+        let (name, fields, methods) = stmt.extract();
+        if let Some(env) = self.env.clone() {
+            let mut fields_ = Vec::with_capacity(fields.len());
+            for (field, default) in fields {
+                let default = match default {
+                    Some(default) => Some(self.evaluate(default.deref())?),
+                    None => None,
+                };
+                fields_.push((field.get_lexeme().to_string(), default));
+            }
+
+            let mut methods_ = HashMap::with_capacity(methods.len());
+            for method in methods {
+                let func = Object::function(method.clone(), self.env.clone(), false);
+                methods_.insert(method.get_name().get_lexeme().to_string(), func);
+            }
+
+            let structure = Object::structure(name.get_lexeme(), fields_, methods_);
+            Interpreter::define(&env, name, Some(Object::Rc(rc!(structure))))?;
+            return Ok(Object::Nil);
+        }
+        Err(RuntimeError::new(name.clone(), RuntimeErrorType::BugEnvironmentNotInit).into())
+    }
+
+    fn visit_enum(&mut self, stmt: &Enum) -> Result<Object> {
+        let (name, variants) = stmt.extract();
+        let variants = variants
+            .iter()
+            .map(|(variant, payload)| {
+                (
+                    variant.get_lexeme().to_string(),
+                    payload
+                        .iter()
+                        .map(|param| param.get_lexeme().to_string())
+                        .collect(),
+                )
+            })
+            .collect();
+        let enumeration = object::enumeration::Enum::new(name.get_lexeme().to_string(), variants);
+
+        match &self.env {
+            None => {
+                Err(RuntimeError::new(name.clone(), RuntimeErrorType::BugEnvironmentNotInit).into())
+            }
+            Some(env) => {
+                Interpreter::define(env, name, Some(Object::Enum(rc!(enumeration))))?;
+                Ok(Object::Nil)
+            }
+        }
+    }
+
+    fn visit_destructure(&mut self, stmt: &Destructure<Result<Object>>) -> Result<Object> {
+        let (pattern, initializer) = stmt.extract();
+        let value = self.evaluate(initializer)?;
+        self.bind_pattern(pattern, value, stmt.is_const())?;
+        Ok(Object::Nil)
+    }
+
+    fn visit_for_in(&mut self, stmt: &ForIn<Result<Object>>) -> Result<Object> {
+        let (keyword, pattern, iterable, body) = stmt.extract();
+        let iterable = self.evaluate(iterable)?;
+        let iterator = match self.iterator(iterable) {
+            Ok(iterator) => iterator,
+            Err(err) => return Interpreter::handle_runtime_error(keyword.clone(), Err(err)),
+        };
+
+        loop {
+            let value = match iterator.next(self) {
+                Ok(Some(value)) => value,
+                Ok(None) => break,
+                Err(err) => return Interpreter::handle_runtime_error(keyword.clone(), Err(err)),
+            };
+
+            let env = Environment::new(self.env.clone());
+            let previous = self.env.replace(Rc::new(RefCell::new(env)));
+            let res = self
+                .bind_pattern(pattern, value, false)
+                .and_then(|_| self.execute(body));
+            self.env = previous;
+            res?;
+        }
         Ok(Object::Nil)
     }
 }
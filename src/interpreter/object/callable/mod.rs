@@ -1,16 +1,21 @@
+use crate::interpreter::Interpreter;
 use crate::interpreter::ast::stmt::fun_stmt::Fun;
+use crate::interpreter::coroutine::Coroutine;
 use crate::interpreter::environment::Environment;
 use crate::interpreter::error::{InterpreterError, Result};
 use crate::interpreter::object::Object;
-use crate::interpreter::scanner::token::token_type::TokenType;
+use crate::interpreter::object::generator::Generator;
+use crate::interpreter::object::native_object::NativeObject;
+use crate::interpreter::object::task::{Task, TaskKind};
 use crate::interpreter::scanner::token::Token;
-use crate::interpreter::Interpreter;
+use crate::interpreter::scanner::token::token_type::TokenType;
 use crate::rc;
 use std::cell::RefCell;
 use std::fmt::{Debug, Display, Formatter};
 use std::rc::Rc;
 
-type CallFn = Rc<dyn Fn(&mut Interpreter, Vec<Object>) -> Result<Object>>;
+pub type CallFn = Rc<dyn Fn(&mut Interpreter, Vec<Object>) -> Result<Object>>;
+type DefaultFn = Rc<dyn Fn(usize) -> Option<Object>>;
 
 #[derive(Clone)]
 pub struct Callable {
@@ -21,6 +26,8 @@ pub struct Callable {
     arity: Rc<dyn Fn() -> usize>,
     to_string: Rc<dyn Fn() -> String>,
     is_init: bool,
+    params: Option<Rc<Vec<String>>>,
+    defaults: Option<DefaultFn>,
 }
 
 impl Callable {
@@ -29,23 +36,45 @@ impl Callable {
         closure: Option<Rc<RefCell<Environment>>>,
         is_init: bool,
     ) -> Self {
+        let generator = declaration.clone().unwrap().borrow().is_generator();
+        let is_async = declaration.clone().unwrap().borrow().is_async();
         let (id, name, params, body) = declaration.clone().unwrap().borrow().clone().extract();
         let arity = params.len();
         let lexeme = name.get_lexeme().to_string();
+        let param_names = params
+            .iter()
+            .map(|param| param.get_lexeme().to_string())
+            .collect();
         Self {
             id,
             declaration,
             closure: closure.clone(),
             is_init,
+            params: Some(rc!(param_names)),
+            defaults: None,
             call: rc!(move |interpreter, args| {
                 let body = body.clone();
                 let mut env = Environment::new(closure.clone());
                 for i in 0..arity {
-                    env.define(params[i].get_lexeme(), Some(args[i].clone()));
+                    env.define(params[i].get_lexeme(), Some(args[i].clone()))?;
                 }
 
                 let closure = Rc::new(RefCell::new(env));
 
+                if is_async {
+                    let coroutine = Coroutine::new(body, closure);
+                    return Ok(Object::NativeObject(NativeObject::new(Box::new(
+                        Task::new(name.get_lexeme(), TaskKind::Coroutine(coroutine)),
+                    ))));
+                }
+
+                if generator {
+                    let coroutine = Coroutine::new(body, closure);
+                    return Ok(Object::NativeObject(NativeObject::new(Box::new(
+                        Generator::new(name.get_lexeme(), coroutine),
+                    ))));
+                }
+
                 match interpreter
                     .execute_block(body.iter().map(AsRef::as_ref).collect(), closure.clone())
                 {
@@ -108,9 +137,21 @@ impl Callable {
             arity,
             to_string,
             is_init,
+            params: None,
+            defaults: None,
         }
     }
 
+    pub fn with_params(mut self, params: Vec<String>) -> Self {
+        self.params = Some(rc!(params));
+        self
+    }
+
+    pub fn with_defaults(mut self, defaults: DefaultFn) -> Self {
+        self.defaults = Some(defaults);
+        self
+    }
+
     pub fn call(&self, interpreter: &mut Interpreter, arguments: Vec<Object>) -> Result<Object> {
         (self.call)(interpreter, arguments)
     }
@@ -133,6 +174,14 @@ impl Callable {
     pub fn is_init(&self) -> bool {
         self.is_init
     }
+
+    pub fn get_params(&self) -> Option<Rc<Vec<String>>> {
+        self.params.clone()
+    }
+
+    pub fn get_default(&self, index: usize) -> Option<Object> {
+        self.defaults.as_ref().and_then(|defaults| defaults(index))
+    }
 }
 
 impl Debug for Callable {
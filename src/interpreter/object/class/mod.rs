@@ -1,31 +1,44 @@
+use crate::interpreter::error::{RuntimeError, RuntimeErrorType};
+use crate::interpreter::object::Object;
 use crate::interpreter::object::callable::Callable;
 use crate::interpreter::object::instance::Instance;
-use crate::interpreter::object::Object;
+use crate::interpreter::scanner::token::Token;
 use crate::rc;
 use crate::utils::next_id;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::rc::Rc;
 
+pub type Fields = Rc<Vec<(String, Option<Object>)>>;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Class {
     id: u64,
     name: Rc<String>,
     methods: Rc<HashMap<String, Object>>,
     superclass: Option<Object>,
+    fields: Option<Fields>,
 }
 
 impl Class {
-    pub fn new(
-        name: String,
-        methods: HashMap<String, Object>,
-        superclass: Option<Object>,
-    ) -> Self {
+    pub fn new(name: String, methods: HashMap<String, Object>, superclass: Option<Object>) -> Self {
         Self {
             id: next_id(),
             name: rc!(name),
             methods: rc!(methods),
             superclass,
+            fields: None,
+        }
+    }
+
+    pub fn new_struct(
+        name: String,
+        fields: Vec<(String, Option<Object>)>,
+        methods: HashMap<String, Object>,
+    ) -> Self {
+        Self {
+            fields: Some(rc!(fields)),
+            ..Self::new(name, methods, None)
         }
     }
 
@@ -35,12 +48,133 @@ impl Class {
         } else if let Some(superclass) = self.superclass.clone() {
             match superclass.inner() {
                 Object::Class(class) => class.find_method(name),
-                _ => panic!("Interpreter bug!")
+                _ => panic!("Interpreter bug!"),
             }
         } else {
             None
         }
     }
+
+    /// The method handed to its decorators: called while a decorated method
+    /// runs, it binds `self` to the instance that method was looked up on.
+    pub fn unbound_method(method: Object, name: Token) -> Object {
+        let Object::Callable(callable) = &method else {
+            panic!("Interpreter bug!");
+        };
+        let arity = callable.arity();
+        let params = callable.get_params().map(|params| params.to_vec());
+        let lexeme = name.get_lexeme().to_string();
+        let unbound = Callable::build(
+            next_id(),
+            None,
+            None,
+            rc!(move |interpreter, args| {
+                let Some(receiver) = interpreter.receiver.clone() else {
+                    return Err(RuntimeError::new(
+                        name.clone(),
+                        RuntimeErrorType::MethodWithoutInstance(name.get_lexeme().to_string()),
+                    )
+                    .into());
+                };
+                match method.bind(receiver)? {
+                    Object::Callable(callable) => callable.call(interpreter, args),
+                    _ => panic!("Interpreter bug!"),
+                }
+            }),
+            rc!(move || arity),
+            rc!(move || lexeme.clone()),
+            false,
+        );
+        Object::Callable(match params {
+            Some(params) => unbound.with_params(params),
+            None => unbound,
+        })
+    }
+
+    /// What the decorators of a method returned, stored once in the class.
+    /// Binding it makes the instance the receiver of the unbound method for
+    /// the duration of each call, see `Object::bind`.
+    pub fn decorated_method(decorated: Callable) -> Object {
+        let arity = decorated.arity();
+        let params = decorated.get_params().map(|params| params.to_vec());
+        let lexeme = decorated.get_string();
+        let method = Callable::build(
+            next_id(),
+            None,
+            None,
+            rc!(move |interpreter, args| decorated.call(interpreter, args)),
+            rc!(move || arity),
+            rc!(move || lexeme.clone()),
+            false,
+        );
+        Object::Callable(match params {
+            Some(params) => method.with_params(params),
+            None => method,
+        })
+    }
+
+    pub fn is_subclass_of(&self, other: &Class) -> bool {
+        if self.id == other.id {
+            return true;
+        }
+        match self.superclass.as_ref().map(Object::inner) {
+            Some(Object::Class(superclass)) => superclass.is_subclass_of(other),
+            _ => false,
+        }
+    }
+
+    /// Names of the methods reachable through `find_method`, inherited ones included.
+    pub fn method_names(&self) -> Vec<String> {
+        let mut names = self.methods.keys().cloned().collect::<Vec<_>>();
+        if let Some(Object::Class(superclass)) = self.superclass.as_ref().map(Object::inner) {
+            names.extend(superclass.method_names());
+        }
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    pub fn get_superclass(&self) -> Option<Object> {
+        self.superclass.clone()
+    }
+
+    pub fn get_fields(&self) -> Option<Fields> {
+        self.fields.clone()
+    }
+
+    pub fn is_struct(&self) -> bool {
+        self.fields.is_some()
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn struct_constructor(value: Class, fields: Fields) -> Callable {
+        let name = value.name.clone();
+        let arity = fields.len();
+        let params = fields.iter().map(|(field, _)| field.clone()).collect();
+        let defaults = fields.clone();
+        Callable::build(
+            value.id,
+            None,
+            None,
+            rc!(move |_, args| {
+                let instance = Instance::new(value.clone());
+                for ((field, _), arg) in fields.iter().zip(args) {
+                    instance.define_field(field, arg);
+                }
+                Ok(Object::Instance(instance))
+            }),
+            rc!(move || arity),
+            rc!(move || name.to_string()),
+            true,
+        )
+        .with_params(params)
+        .with_defaults(rc!(move |index| defaults
+            .get(index)
+            .and_then(|(_, default)| default.clone())))
+    }
 }
 
 impl Display for Class {
@@ -51,6 +185,10 @@ impl Display for Class {
 
 impl From<Class> for Callable {
     fn from(value: Class) -> Self {
+        if let Some(fields) = value.fields.clone() {
+            return Class::struct_constructor(value, fields);
+        }
+
         let name = value.name.clone();
         let value_call = value.clone();
         let value_arity = value.clone();
@@ -61,14 +199,16 @@ impl From<Class> for Callable {
             None,
             rc!(move |interpreter, args| {
                 let instance = Instance::new(value_call.clone());
-
-                if let Some(initializer) = value_call.find_method("init") {
-                    match initializer.bind(instance.clone())? {
-                        Object::Callable(callable) => {
-                            callable.call(interpreter, args)?;
-                        }
-                        _ => panic!("Interpreter bug!"),
+                let initializer = value_call
+                    .find_method("init")
+                    .map(|initializer| initializer.bind(instance.clone()))
+                    .transpose()?;
+                match initializer {
+                    Some(Object::Callable(callable)) => {
+                        callable.call(interpreter, args)?;
                     }
+                    Some(_) => return Err(RuntimeErrorType::NotCallable.into()),
+                    None => {}
                 }
 
                 Ok(Object::Instance(instance))
@@ -0,0 +1,166 @@
+use crate::interpreter::error::Result;
+use crate::interpreter::error::{RuntimeError, RuntimeErrorType};
+use crate::interpreter::object::Object;
+use crate::interpreter::object::callable::Callable;
+use crate::interpreter::scanner::token::Token;
+use crate::rc;
+use crate::utils::next_id;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+#[derive(Debug)]
+pub struct Enum {
+    id: u64,
+    name: String,
+    variants: Vec<(String, Vec<String>)>,
+}
+
+impl Enum {
+    pub fn new(name: String, variants: Vec<(String, Vec<String>)>) -> Self {
+        Self {
+            id: next_id(),
+            name,
+            variants,
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_variants(&self) -> &Vec<(String, Vec<String>)> {
+        &self.variants
+    }
+
+    /// Looks up a variant by name: unit variants are values, the others are
+    /// constructors taking their payload.
+    pub fn get(this: &Rc<Enum>, name: &Token) -> Result<Object> {
+        let tag = match this
+            .variants
+            .iter()
+            .position(|(variant, _)| variant == name.get_lexeme())
+        {
+            Some(tag) => tag,
+            None => {
+                return Err(RuntimeError::new(
+                    name.clone(),
+                    RuntimeErrorType::UndefinedVariant(
+                        this.name.clone(),
+                        name.get_lexeme().to_string(),
+                    ),
+                )
+                .into());
+            }
+        };
+
+        let (variant, params) = this.variants[tag].clone();
+        if params.is_empty() {
+            return Ok(Object::Variant(Variant::new(this.clone(), tag, vec![])));
+        }
+
+        let enumeration = this.clone();
+        let arity = params.len();
+        Ok(Object::Callable(
+            Callable::build(
+                next_id(),
+                None,
+                None,
+                rc!(move |_, args| Ok(Object::Variant(Variant::new(
+                    enumeration.clone(),
+                    tag,
+                    args
+                )))),
+                rc!(move || arity),
+                rc!(move || variant.clone()),
+                false,
+            )
+            .with_params(params),
+        ))
+    }
+}
+
+impl Display for Enum {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl PartialEq for Enum {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variant {
+    enumeration: Rc<Enum>,
+    tag: usize,
+    payload: Vec<Object>,
+}
+
+impl Variant {
+    pub fn new(enumeration: Rc<Enum>, tag: usize, payload: Vec<Object>) -> Self {
+        Self {
+            enumeration,
+            tag,
+            payload,
+        }
+    }
+
+    pub fn get_enum(&self) -> &Enum {
+        &self.enumeration
+    }
+
+    pub fn get_enum_rc(&self) -> Rc<Enum> {
+        self.enumeration.clone()
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.enumeration.variants[self.tag].0
+    }
+
+    pub fn get_payload(&self) -> &Vec<Object> {
+        &self.payload
+    }
+
+    /// `variant` and `payload` describe the value itself, any other name reads
+    /// the payload field declared under that name.
+    pub fn get(&self, name: &Token) -> Result<Object> {
+        match name.get_lexeme() {
+            "variant" => Ok(Object::String(self.get_name().to_string())),
+            "payload" => Ok(Object::List(self.payload.clone())),
+            field => {
+                let (_, params) = &self.enumeration.variants[self.tag];
+                match params.iter().position(|param| param == field) {
+                    Some(index) => Ok(self.payload[index].clone()),
+                    None => Err(RuntimeError::new(
+                        name.clone(),
+                        RuntimeErrorType::UndefinedProperty(field.to_string()),
+                    )
+                    .into()),
+                }
+            }
+        }
+    }
+}
+
+impl Display for Variant {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.enumeration, self.get_name())?;
+        if !self.payload.is_empty() {
+            write!(
+                f,
+                "({})",
+                self.payload
+                    .iter()
+                    .map(|obj| match obj {
+                        Object::String(str) => format!("{:?}", str),
+                        _ => obj.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
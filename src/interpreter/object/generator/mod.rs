@@ -0,0 +1,100 @@
+use crate::interpreter::Interpreter;
+use crate::interpreter::coroutine::{Coroutine, Step};
+use crate::interpreter::error::{Result, RuntimeErrorType};
+use crate::interpreter::object::Object;
+use crate::interpreter::object::iterator::NativeIterator;
+use crate::interpreter::object::native_object::Native;
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+/// Object returned by calling a function that contains `yield`. Clones share
+/// the same suspended body.
+#[derive(Clone)]
+pub struct Generator {
+    name: String,
+    coroutine: Rc<RefCell<Option<Coroutine>>>,
+}
+
+impl Generator {
+    pub fn new(name: &str, coroutine: Coroutine) -> Self {
+        Self {
+            name: name.to_string(),
+            coroutine: Rc::new(RefCell::new(Some(coroutine))),
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Resumes the body with `sent` as the value of the pending `yield`. The
+    /// coroutine is taken out while it runs so a generator cannot resume
+    /// itself.
+    pub fn resume(&self, interpreter: &mut Interpreter, sent: Object) -> Result<Step> {
+        let mut coroutine = self
+            .coroutine
+            .borrow_mut()
+            .take()
+            .ok_or_else(|| RuntimeErrorType::GeneratorAlreadyRunning(self.name.clone()))?;
+        let res = coroutine.resume(interpreter, sent);
+        self.coroutine.replace(Some(coroutine));
+        res
+    }
+
+    /// Value produced by `send`: the next yielded value, or the returned
+    /// value once the body completes.
+    pub fn send(&self, interpreter: &mut Interpreter, sent: Object) -> Result<Object> {
+        match self.resume(interpreter, sent)? {
+            Step::Yield(value) | Step::Await(value) | Step::Complete(value) => Ok(value),
+        }
+    }
+
+    /// Finishes the generator without running the rest of its body.
+    pub fn finish(&self, value: Object) -> Result<Object> {
+        match self.coroutine.borrow_mut().as_mut() {
+            Some(coroutine) => {
+                coroutine.finish();
+                Ok(value)
+            }
+            None => Err(RuntimeErrorType::GeneratorAlreadyRunning(self.name.clone()).into()),
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.coroutine
+            .borrow()
+            .as_ref()
+            .is_some_and(Coroutine::is_finished)
+    }
+
+    pub fn iter(&self) -> NativeIterator {
+        let generator = self.clone();
+        NativeIterator::new(move |interpreter| {
+            match generator.resume(interpreter, Object::Nil)? {
+                Step::Yield(value) | Step::Await(value) => Ok(Some(value)),
+                Step::Complete(_) => Ok(None),
+            }
+        })
+    }
+}
+
+impl Debug for Generator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<generator {}>", self.name)
+    }
+}
+
+impl Native for Generator {
+    fn clone_box(&self) -> Box<dyn Native> {
+        Box::new(self.clone())
+    }
+
+    fn type_name(&self) -> String {
+        "generator".into()
+    }
+
+    fn display(&self) -> String {
+        format!("<generator {}>", self.name)
+    }
+}
@@ -1,8 +1,11 @@
 use crate::interpreter::error::Result;
 use crate::interpreter::error::{RuntimeError, RuntimeErrorType};
-use crate::interpreter::object::class::Class;
 use crate::interpreter::object::Object;
+use crate::interpreter::object::callable::Callable;
+use crate::interpreter::object::class::{Class, Fields};
 use crate::interpreter::scanner::token::Token;
+use crate::rc;
+use crate::utils::next_id;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
@@ -22,7 +25,34 @@ impl Instance {
         }
     }
 
-    pub fn get(&self, name: &Token) -> Result<Object> {
+    /// Members prefixed with `_` are private: only methods of a class on the
+    /// instance's inheritance line (`accessor`), superclasses included, may
+    /// reach them.
+    pub fn is_private_member(name: &str) -> bool {
+        name.starts_with('_')
+    }
+
+    fn check_access(&self, name: &Token, accessor: Option<&Class>) -> Result<()> {
+        if !Instance::is_private_member(name.get_lexeme()) {
+            return Ok(());
+        }
+        match accessor {
+            Some(accessor)
+                if accessor.is_subclass_of(&self.class) || self.class.is_subclass_of(accessor) =>
+            {
+                Ok(())
+            }
+            _ => Err(RuntimeError::new(
+                name.clone(),
+                RuntimeErrorType::PrivateMemberAccess(name.get_lexeme().to_string()),
+            )
+            .into()),
+        }
+    }
+
+    pub fn get(&self, name: &Token, accessor: Option<&Class>) -> Result<Object> {
+        self.check_access(name, accessor)?;
+
         if let Some(obj) = self.fields.borrow().get(name.get_lexeme()) {
             return Ok(obj.clone());
         }
@@ -31,6 +61,10 @@ impl Instance {
             return method.bind(self.clone());
         }
 
+        if let (Some(fields), "copy") = (self.class.get_fields(), name.get_lexeme()) {
+            return Ok(Object::Callable(self.copy_method(fields)));
+        }
+
         Err(RuntimeError::new(
             name.clone(),
             RuntimeErrorType::UndefinedProperty(name.get_lexeme().to_string()),
@@ -38,15 +72,88 @@ impl Instance {
         .into())
     }
 
-    pub fn set(&self, name: &Token, value: Object) {
-        self.fields
-            .borrow_mut()
-            .insert(name.get_lexeme().to_string(), value);
+    pub fn set(&self, name: &Token, value: Object, accessor: Option<&Class>) -> Result<()> {
+        self.check_access(name, accessor)?;
+        self.define_field(name.get_lexeme(), value);
+        Ok(())
+    }
+
+    pub fn define_field(&self, name: &str, value: Object) {
+        self.fields.borrow_mut().insert(name.to_string(), value);
+    }
+
+    pub fn get_class(&self) -> &Class {
+        &self.class
+    }
+
+    /// Names of the public fields, in declaration order for structs.
+    pub fn field_names(&self) -> Vec<String> {
+        let mut names = match self.class.get_fields() {
+            Some(fields) => fields.iter().map(|(field, _)| field.clone()).collect(),
+            None => {
+                let mut names = self.fields.borrow().keys().cloned().collect::<Vec<_>>();
+                names.sort();
+                names
+            }
+        };
+        names.retain(|name| !Instance::is_private_member(name));
+        names
+    }
+
+    /// Builds the `copy` method of a struct instance: every field is an optional
+    /// parameter defaulting to its current value, so `p.copy(y: 1)` updates only `y`.
+    fn copy_method(&self, fields: Fields) -> Callable {
+        let class = self.class.clone();
+        let arity = fields.len();
+        let params = fields.iter().map(|(field, _)| field.clone()).collect();
+        let current = fields
+            .iter()
+            .map(|(field, _)| self.fields.borrow().get(field).cloned())
+            .collect::<Vec<_>>();
+        Callable::build(
+            next_id(),
+            None,
+            None,
+            rc!(move |_, args| {
+                let instance = Instance {
+                    class: class.clone(),
+                    fields: Default::default(),
+                };
+                for ((field, _), arg) in fields.iter().zip(args) {
+                    instance.define_field(field, arg);
+                }
+                Ok(Object::Instance(instance))
+            }),
+            rc!(move || arity),
+            rc!(|| "copy".into()),
+            false,
+        )
+        .with_params(params)
+        .with_defaults(rc!(move |index| current.get(index).cloned().flatten()))
     }
 }
 
 impl Display for Instance {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} instance", self.class)
+        match self.class.get_fields() {
+            Some(fields) => {
+                let values = self.fields.borrow();
+                write!(
+                    f,
+                    "{}({})",
+                    self.class,
+                    fields
+                        .iter()
+                        .map(|(field, _)| match values.get(field) {
+                            Some(Object::String(str)) => format!("{}: {:?}", field, str),
+                            Some(value) => format!("{}: {}", field, value),
+                            None => format!("{}: nil", field),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+            None => write!(f, "{} instance", self.class),
+        }
     }
 }
@@ -0,0 +1,75 @@
+use crate::interpreter::Interpreter;
+use crate::interpreter::error::Result;
+use crate::interpreter::object::Object;
+use crate::interpreter::object::native_object::Native;
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+type NextFn = Rc<RefCell<dyn FnMut(&mut Interpreter) -> Result<Option<Object>>>>;
+
+/// Iterator produced by `iter` and consumed by `for ... in`. Clones share the
+/// same position.
+#[derive(Clone)]
+pub struct NativeIterator {
+    next: NextFn,
+}
+
+impl NativeIterator {
+    pub fn new(next: impl FnMut(&mut Interpreter) -> Result<Option<Object>> + 'static) -> Self {
+        Self {
+            next: Rc::new(RefCell::new(next)),
+        }
+    }
+
+    pub fn from_values(values: Vec<Object>) -> Self {
+        let mut values = values.into_iter();
+        Self::new(move |_| Ok(values.next()))
+    }
+
+    pub fn next(&self, interpreter: &mut Interpreter) -> Result<Option<Object>> {
+        (self.next.borrow_mut())(interpreter)
+    }
+}
+
+impl Debug for NativeIterator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NativeIterator {{ ... }}")
+    }
+}
+
+impl Native for NativeIterator {
+    fn clone_box(&self) -> Box<dyn Native> {
+        Box::new(self.clone())
+    }
+}
+
+/// The `done` builtin: returned by `next()` of an instance, it ends a
+/// `for ... in` loop, so that `nil` can be iterated like any other value.
+#[derive(Debug, Clone)]
+pub struct Done;
+
+impl Done {
+    pub fn is(obj: &Object) -> bool {
+        match obj.inner() {
+            Object::NativeObject(native) => {
+                native.clone().extract().downcast_ref::<Done>().is_some()
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Native for Done {
+    fn clone_box(&self) -> Box<dyn Native> {
+        Box::new(self.clone())
+    }
+
+    fn type_name(&self) -> String {
+        "done".into()
+    }
+
+    fn display(&self) -> String {
+        "done".into()
+    }
+}
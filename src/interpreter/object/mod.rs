@@ -1,23 +1,34 @@
+use crate::b;
 use crate::interpreter::ast::stmt::fun_stmt::Fun;
 use crate::interpreter::environment::Environment;
 use crate::interpreter::error::RuntimeErrorType;
 use crate::interpreter::error::{InterpreterError, Result};
 use crate::interpreter::object::callable::Callable;
 use crate::interpreter::object::class::Class;
+use crate::interpreter::object::enumeration::{Enum, Variant};
 use crate::interpreter::object::instance::Instance;
+use crate::interpreter::object::iterator::Done;
 use crate::interpreter::object::native_object::NativeObject;
+use crate::interpreter::object::range::Range;
+use crate::rc;
+use crate::utils::next_id;
 use std::cell::RefCell;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt::{Debug, Display};
 use std::ops::{Add, Deref, Div, Mul, Neg, Not, Sub};
 use std::rc::Rc;
-use crate::b;
 
 pub mod callable;
 pub mod class;
+pub mod enumeration;
+pub mod generator;
 pub mod instance;
+pub mod iterator;
+pub mod module;
 pub mod native_object;
+pub mod range;
+pub mod task;
 
 #[derive(Debug, Clone)]
 pub enum Object {
@@ -32,6 +43,9 @@ pub enum Object {
     Nil,
     Void,
     List(Vec<Object>),
+    Enum(Rc<Enum>),
+    Variant(Variant),
+    Range(Range),
 }
 
 impl Object {
@@ -44,10 +58,13 @@ impl Object {
             Object::Void => "void".into(),
             Object::Callable { .. } => "<callable>".into(),
             Object::Class(class) => class.to_string(),
-            Object::Instance(instance) => instance.to_string(),
-            Object::NativeObject(_) => "<native object>".into(),
+            Object::Instance(instance) => format!("{} instance", instance.get_class()),
+            Object::NativeObject(native) => native.get_type(),
             Object::Rc(obj) => obj.get_type(),
             Object::List(_) => "list".into(),
+            Object::Enum(enumeration) => enumeration.to_string(),
+            Object::Variant(variant) => format!("{} variant", variant.get_enum()),
+            Object::Range(_) => "range".into(),
         }
     }
 
@@ -63,19 +80,49 @@ impl Object {
         ))
     }
 
-    pub fn class(
+    pub fn class(name: &str, methods: HashMap<String, Object>, superclass: Option<Object>) -> Self {
+        Self::Class(b!(Class::new(name.to_string(), methods, superclass)))
+    }
+
+    pub fn structure(
         name: &str,
+        fields: Vec<(String, Option<Object>)>,
         methods: HashMap<String, Object>,
-        superclass: Option<Object>,
     ) -> Self {
-        Self::Class(b!(Class::new(name.to_string(), methods, superclass)))
+        Self::Class(b!(Class::new_struct(name.to_string(), fields, methods)))
     }
 
+    /// Binds `self` of a method to `obj`. A decorated method has no
+    /// declaration: `obj` is its receiver while the call lasts.
     pub fn bind(&self, obj: Instance) -> Result<Object> {
         match self {
+            Object::Callable(callable) if callable.get_declaration().is_none() => {
+                let method = callable.clone();
+                let arity = method.arity();
+                let params = method.get_params().map(|params| params.to_vec());
+                let lexeme = method.get_string();
+                let bound = Callable::build(
+                    next_id(),
+                    None,
+                    None,
+                    rc!(move |interpreter, args| {
+                        let receiver = interpreter.receiver.replace(obj.clone());
+                        let result = method.call(interpreter, args);
+                        interpreter.receiver = receiver;
+                        result
+                    }),
+                    rc!(move || arity),
+                    rc!(move || lexeme.clone()),
+                    false,
+                );
+                Ok(Object::Callable(match params {
+                    Some(params) => bound.with_params(params),
+                    None => bound,
+                }))
+            }
             Object::Callable(callable) => {
                 let mut env = Environment::new(callable.get_closure());
-                env.define("self", Some(Object::Instance(obj)));
+                env.define("self", Some(Object::Instance(obj)))?;
                 Ok(Object::Callable(Callable::new(
                     callable.get_declaration(),
                     Some(Rc::new(RefCell::new(env))),
@@ -85,20 +132,121 @@ impl Object {
             _ => panic!("Interpreter bug"),
         }
     }
-    
+
+    /// Membership test behind the `in` operator.
+    pub fn contains(&self, item: &Object) -> Result<bool> {
+        match (self.inner(), item.inner()) {
+            (Object::List(values), item) => Ok(values.iter().any(|value| value == item)),
+            (Object::String(str), Object::String(sub)) => Ok(str.contains(sub.as_str())),
+            (Object::Range(range), Object::Number(n)) => Ok(range.contains(*n)),
+            (Object::Range(_), _) => Ok(false),
+            _ => Err(
+                RuntimeErrorType::CannotCheckMembership(item.get_type(), self.get_type()).into(),
+            ),
+        }
+    }
+
+    pub fn get_len(&self) -> Option<usize> {
+        match self.inner() {
+            Object::List(values) => Some(values.len()),
+            Object::String(str) => Some(str.chars().count()),
+            Object::Range(range) => Some(range.len()),
+            _ => None,
+        }
+    }
+
+    /// Element access behind `xs[i]`, counting negative indices from the end.
+    pub fn index(&self, index: &Object) -> Result<Object> {
+        let len = self
+            .get_len()
+            .ok_or_else(|| RuntimeErrorType::CannotIndex(self.get_type()))?;
+        let Object::Number(index) = index.inner() else {
+            return Err(RuntimeErrorType::IndexMustBeNumber(index.get_type()).into());
+        };
+        let index = Object::integer(*index)?;
+        let index = if index < 0.0 {
+            index + len as f64
+        } else {
+            index
+        };
+        if index < 0.0 || index >= len as f64 {
+            return Ok(Object::Nil);
+        }
+        let index = index as usize;
+        match self.inner() {
+            Object::List(values) => Ok(values[index].clone()),
+            Object::String(str) => Ok(Object::String(
+                str.chars().nth(index).map(String::from).unwrap_or_default(),
+            )),
+            Object::Range(range) => Ok(range.nth(index).map_or(Object::Nil, Object::Number)),
+            _ => Err(RuntimeErrorType::CannotIndex(self.get_type()).into()),
+        }
+    }
+
+    /// Indices and slice bounds must be whole numbers.
+    fn integer(value: f64) -> Result<f64> {
+        if value.fract() != 0.0 {
+            return Err(RuntimeErrorType::IndexMustBeInteger(value.to_string()).into());
+        }
+        Ok(value)
+    }
+
+    /// Slice behind `xs[start:end:step]`, following Python's rules for
+    /// negative and omitted bounds. Strings are sliced by character.
+    pub fn slice(&self, start: Option<f64>, end: Option<f64>, step: Option<f64>) -> Result<Object> {
+        let start = start.map(Object::integer).transpose()?;
+        let end = end.map(Object::integer).transpose()?;
+        let step = step.map(Object::integer).transpose()?.unwrap_or(1.0) as i64;
+        if step == 0 {
+            return Err(RuntimeErrorType::SliceStepMustBeNonZero.into());
+        }
+        let len = match self.inner() {
+            Object::List(_) | Object::String(_) => self.get_len().unwrap_or_default() as i64,
+            _ => return Err(RuntimeErrorType::CannotIndex(self.get_type()).into()),
+        };
+        let (lower, upper) = if step > 0 { (0, len) } else { (-1, len - 1) };
+        let bound = |value: Option<f64>, default: i64| match value {
+            None => default,
+            Some(value) if value < 0.0 => (value as i64 + len).max(lower),
+            Some(value) => (value as i64).min(upper),
+        };
+        let start = bound(start, if step > 0 { lower } else { upper });
+        let end = bound(end, if step > 0 { upper } else { lower });
+
+        let mut indices = vec![];
+        let mut i = start;
+        while (step > 0 && i < end) || (step < 0 && i > end) {
+            indices.push(i as usize);
+            i += step;
+        }
+
+        match self.inner() {
+            Object::List(values) => Ok(Object::List(
+                indices.into_iter().map(|i| values[i].clone()).collect(),
+            )),
+            Object::String(str) => {
+                let chars = str.chars().collect::<Vec<_>>();
+                Ok(Object::String(
+                    indices.into_iter().map(|i| chars[i]).collect(),
+                ))
+            }
+            _ => Err(RuntimeErrorType::CannotIndex(self.get_type()).into()),
+        }
+    }
+
     pub fn clone_into_rc(&self) -> Self {
         match self {
             Object::Rc(obj) => obj.clone().deref().clone(),
-            _ => self.clone()
+            _ => self.clone(),
         }
     }
-    
+
     pub fn inner(&self) -> &Self {
         match self {
             Object::Rc(rc) => rc.inner(),
             _ => self,
         }
-    } 
+    }
 }
 
 impl Neg for Object {
@@ -151,7 +299,7 @@ impl Add for Object {
                 new.append(&mut a.clone());
                 new.push(rhs.clone());
                 Ok(Object::List(new))
-            },
+            }
             (Object::Rc(rc), _) => rc.clone_into_rc() + rhs,
             (_, Object::Rc(rc)) => self + rc.clone_into_rc(),
             _ => Err(RuntimeErrorType::CannotAddTypes(self.get_type(), rhs.get_type()).into()),
@@ -207,8 +355,15 @@ impl PartialEq<Self> for Object {
             (Object::Nil, Object::Nil) => true,
             (Object::Void, Object::Void) => true,
             (Object::Callable(callable), Object::Callable(callable2)) => callable == callable2,
+            (Object::Instance(instance), Object::Instance(instance2)) => instance == instance2,
+            (Object::Class(class), Object::Class(class2)) => class == class2,
+            (Object::Enum(enumeration), Object::Enum(enumeration2)) => enumeration == enumeration2,
+            (Object::Variant(variant), Object::Variant(variant2)) => variant == variant2,
+            (Object::Range(range), Object::Range(range2)) => range == range2,
+            (Object::List(list), Object::List(list2)) => list == list2,
             (Object::Rc(rc), _) => &rc.clone_into_rc() == other,
             (_, Object::Rc(rc)) => self == &rc.clone_into_rc(),
+            (Object::NativeObject(_), Object::NativeObject(_)) => Done::is(self) && Done::is(other),
             _ => false,
         }
     }
@@ -239,14 +394,22 @@ impl Display for Object {
             Object::Callable(callable) => write!(f, "{}", callable),
             Object::Class(class) => write!(f, "{}", class),
             Object::Instance(instance) => write!(f, "{}", instance),
-            Object::NativeObject(_) => write!(f, "<native object>"),
+            Object::NativeObject(native) => write!(f, "{}", native.display()),
             Object::Rc(rc) => write!(f, "{}", rc),
-            Object::List(list) => write!(f, "[{}]", list.iter().map(
-                |obj| match obj {
-                    Object::String(str) => format!("{:?}", str),
-                    _ => obj.to_string(),
-                }
-            ).collect::<Vec<_>>().join(", ")),
+            Object::List(list) => write!(
+                f,
+                "[{}]",
+                list.iter()
+                    .map(|obj| match obj {
+                        Object::String(str) => format!("{:?}", str),
+                        _ => obj.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Object::Enum(enumeration) => write!(f, "{}", enumeration),
+            Object::Variant(variant) => write!(f, "{}", variant),
+            Object::Range(range) => write!(f, "{}", range),
         }
     }
 }
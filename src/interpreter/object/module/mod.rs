@@ -0,0 +1,78 @@
+use crate::interpreter::environment::Environment;
+use crate::interpreter::error::{Result, RuntimeError, RuntimeErrorType};
+use crate::interpreter::object::Object;
+use crate::interpreter::object::native_object::Native;
+use crate::interpreter::scanner::token::Token;
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+/// Namespace bound by `use "path" as name;`. Members are read from the
+/// module's own environment, so `math.square` always sees the module's
+/// current binding, and the export list is shared with the interpreter so a
+/// reloaded module's new exports are visible too.
+#[derive(Clone)]
+pub struct Module {
+    name: String,
+    env: Rc<RefCell<Environment>>,
+    exports: Rc<RefCell<Vec<String>>>,
+}
+
+impl Module {
+    pub fn new(
+        name: &str,
+        env: Rc<RefCell<Environment>>,
+        exports: Rc<RefCell<Vec<String>>>,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            env,
+            exports,
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get(&self, name: &Token) -> Result<Object> {
+        if !self
+            .exports
+            .borrow()
+            .iter()
+            .any(|export| export == name.get_lexeme())
+        {
+            return Err(RuntimeError::new(
+                name.clone(),
+                RuntimeErrorType::UndefinedExport(self.name.clone(), name.get_lexeme().to_string()),
+            )
+            .into());
+        }
+        self.env.borrow().get(name)
+    }
+}
+
+impl Debug for Module {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Module {{ name: {:?}, exports: {:?} }}",
+            self.name,
+            self.exports.borrow()
+        )
+    }
+}
+
+impl Native for Module {
+    fn clone_box(&self) -> Box<dyn Native> {
+        Box::new(self.clone())
+    }
+
+    fn type_name(&self) -> String {
+        "module".into()
+    }
+
+    fn display(&self) -> String {
+        format!("<module {}>", self.name)
+    }
+}
@@ -1,4 +1,4 @@
-use downcast_rs::{impl_downcast, Downcast};
+use downcast_rs::{Downcast, impl_downcast};
 use std::fmt::Debug;
 use std::time::Instant;
 
@@ -15,6 +15,14 @@ impl NativeObject {
     pub fn extract(self) -> Box<dyn Native> {
         self.value
     }
+
+    pub fn get_type(&self) -> String {
+        self.value.type_name()
+    }
+
+    pub fn display(&self) -> String {
+        self.value.display()
+    }
 }
 
 impl Native for Instant {
@@ -25,6 +33,14 @@ impl Native for Instant {
 
 pub trait Native: Debug + Downcast {
     fn clone_box(&self) -> Box<dyn Native>;
+
+    fn type_name(&self) -> String {
+        "<native object>".into()
+    }
+
+    fn display(&self) -> String {
+        "<native object>".into()
+    }
 }
 
 impl_downcast!(Native);
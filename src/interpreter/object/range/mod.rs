@@ -0,0 +1,95 @@
+use crate::interpreter::error::Result;
+use crate::interpreter::error::RuntimeErrorType;
+use crate::interpreter::object::Object;
+use crate::interpreter::object::iterator::NativeIterator;
+use std::fmt::{Display, Formatter};
+
+/// Lazy numeric range produced by `a..b` and `a..=b`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Range {
+    start: f64,
+    end: f64,
+    step: f64,
+    inclusive: bool,
+}
+
+impl Range {
+    pub fn new(start: f64, end: f64, inclusive: bool) -> Self {
+        Self {
+            start,
+            end,
+            step: 1.0,
+            inclusive,
+        }
+    }
+
+    pub fn with_step(&self, step: f64) -> Result<Self> {
+        if step == 0.0 || step.is_nan() {
+            return Err(RuntimeErrorType::RangeStepMustBeNonZero.into());
+        }
+        Ok(Self {
+            step,
+            ..self.clone()
+        })
+    }
+
+    pub fn get_start(&self) -> f64 {
+        self.start
+    }
+
+    pub fn get_end(&self) -> f64 {
+        self.end
+    }
+
+    pub fn len(&self) -> usize {
+        let span = (self.end - self.start) / self.step;
+        let len = if self.inclusive {
+            span.floor() + 1.0
+        } else {
+            span.ceil()
+        };
+        if len.is_finite() && len > 0.0 {
+            len as usize
+        } else {
+            0
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn nth(&self, index: usize) -> Option<f64> {
+        if index < self.len() {
+            Some(self.start + index as f64 * self.step)
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, value: f64) -> bool {
+        let offset = (value - self.start) / self.step;
+        offset >= 0.0 && offset.fract() == 0.0 && (offset as usize) < self.len()
+    }
+
+    pub fn iter(&self) -> NativeIterator {
+        let range = self.clone();
+        let mut index = 0;
+        NativeIterator::new(move |_| {
+            let value = range.nth(index);
+            index += 1;
+            Ok(value.map(Object::Number))
+        })
+    }
+}
+
+impl Display for Range {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let op = if self.inclusive { "..=" } else { ".." };
+        if self.step == 1.0 {
+            write!(f, "{}{}{}", self.start, op, self.end)
+        } else {
+            write!(f, "({}{}{}).step({})", self.start, op, self.end, self.step)
+        }
+    }
+}
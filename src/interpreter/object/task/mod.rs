@@ -0,0 +1,135 @@
+use crate::interpreter::coroutine::Coroutine;
+use crate::interpreter::object::Object;
+use crate::interpreter::object::callable::Callable;
+use crate::interpreter::object::native_object::Native;
+use crate::utils::next_id;
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+/// Work a task performs once the event loop starts it.
+pub enum TaskKind {
+    /// Body of an `async fun` call.
+    Coroutine(Coroutine),
+    /// `sleep(ms)`: completes with `nil` after `ms` of loop time.
+    Sleep(f64),
+    /// `setTimeout(callback, ms)`: completes with the callback's result.
+    Timeout(Callable, f64),
+    /// `join(tasks)`: completes with the list of the tasks' results.
+    Join(Vec<Task>),
+}
+
+enum TaskState {
+    Created,
+    Started,
+    Done(Object),
+}
+
+struct TaskInner {
+    name: String,
+    kind: Option<TaskKind>,
+    state: TaskState,
+    waiters: Vec<Task>,
+}
+
+/// Unit of work scheduled on the interpreter's event loop. Nothing runs
+/// until the task is awaited or spawned; clones refer to the same task.
+#[derive(Clone)]
+pub struct Task {
+    id: u64,
+    inner: Rc<RefCell<TaskInner>>,
+}
+
+impl Task {
+    pub fn new(name: &str, kind: TaskKind) -> Self {
+        Self {
+            id: next_id(),
+            inner: Rc::new(RefCell::new(TaskInner {
+                name: name.to_string(),
+                kind: Some(kind),
+                state: TaskState::Created,
+                waiters: vec![],
+            })),
+        }
+    }
+
+    pub fn from_object(obj: &Object) -> Option<Task> {
+        match obj.inner() {
+            Object::NativeObject(native) => {
+                native.clone().extract().downcast_ref::<Task>().cloned()
+            }
+            _ => None,
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.inner.borrow().name.clone()
+    }
+
+    pub fn is_started(&self) -> bool {
+        !matches!(self.inner.borrow().state, TaskState::Created)
+    }
+
+    pub fn is_done(&self) -> bool {
+        matches!(self.inner.borrow().state, TaskState::Done(_))
+    }
+
+    pub fn get_result(&self) -> Option<Object> {
+        match &self.inner.borrow().state {
+            TaskState::Done(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn mark_started(&self) {
+        self.inner.borrow_mut().state = TaskState::Started;
+    }
+
+    /// Stores the result and hands back the tasks waiting for it.
+    pub fn complete(&self, value: Object) -> Vec<Task> {
+        let mut inner = self.inner.borrow_mut();
+        inner.state = TaskState::Done(value);
+        std::mem::take(&mut inner.waiters)
+    }
+
+    pub fn add_waiter(&self, task: Task) {
+        self.inner.borrow_mut().waiters.push(task);
+    }
+
+    /// Takes the work out while it runs, so a task resuming itself finds
+    /// nothing to do.
+    pub fn take_kind(&self) -> Option<TaskKind> {
+        self.inner.borrow_mut().kind.take()
+    }
+
+    pub fn put_kind(&self, kind: TaskKind) {
+        self.inner.borrow_mut().kind = Some(kind);
+    }
+
+    pub fn delay(&self) -> Option<f64> {
+        match self.inner.borrow().kind {
+            Some(TaskKind::Sleep(delay)) | Some(TaskKind::Timeout(_, delay)) => Some(delay),
+            _ => None,
+        }
+    }
+}
+
+impl Debug for Task {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<task#{} {}>", self.id, self.get_name())
+    }
+}
+
+impl Native for Task {
+    fn clone_box(&self) -> Box<dyn Native> {
+        Box::new(self.clone())
+    }
+
+    fn type_name(&self) -> String {
+        "task".into()
+    }
+
+    fn display(&self) -> String {
+        format!("<task {}>", self.get_name())
+    }
+}
@@ -0,0 +1,55 @@
+use crate::interpreter::error::InterpreterError;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone)]
+pub enum PackageError {
+    ManifestReadFailed(String, String),
+    ManifestInvalid(String, String),
+    DependencyNotFound(String, String),
+    EntryNotFound(String, String),
+    LockWriteFailed(String, String),
+    LockInvalid(String, String),
+    LockMismatch(String, String, String),
+}
+
+impl Display for PackageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PackageError::ManifestReadFailed(path, reason) => {
+                write!(f, "Failed to read manifest '{}': {}", path, reason)
+            }
+            PackageError::ManifestInvalid(path, reason) => {
+                write!(f, "Invalid manifest '{}': {}", path, reason.trim_end())
+            }
+            PackageError::DependencyNotFound(name, dir) => {
+                write!(f, "Dependency '{}' has no yun.toml in '{}'", name, dir)
+            }
+            PackageError::EntryNotFound(name, path) => {
+                write!(
+                    f,
+                    "Entry point of package '{}' not found at '{}'",
+                    name, path
+                )
+            }
+            PackageError::LockWriteFailed(path, reason) => {
+                write!(f, "Failed to write lockfile '{}': {}", path, reason)
+            }
+            PackageError::LockInvalid(path, reason) => {
+                write!(f, "Invalid lockfile '{}': {}", path, reason.trim_end())
+            }
+            PackageError::LockMismatch(path, locked, found) => {
+                write!(
+                    f,
+                    "Package '{}' is locked as {} but its manifest says {}, delete yun.lock to accept it",
+                    path, locked, found
+                )
+            }
+        }
+    }
+}
+
+impl From<PackageError> for InterpreterError {
+    fn from(value: PackageError) -> Self {
+        InterpreterError::PackageError(value)
+    }
+}
@@ -0,0 +1,224 @@
+use crate::interpreter::package::error::PackageError;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+pub mod error;
+
+pub const MANIFEST: &str = "yun.toml";
+pub const LOCKFILE: &str = "yun.lock";
+
+/// Contents of a `yun.toml`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    package: Info,
+    #[serde(default)]
+    dependencies: BTreeMap<String, Dependency>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Info {
+    name: String,
+    version: String,
+    #[serde(default = "default_entry")]
+    entry: PathBuf,
+}
+
+fn default_entry() -> PathBuf {
+    PathBuf::from("index.yun")
+}
+
+/// A dependency is a local directory holding another package, given
+/// relative to the manifest that declares it.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Dependency {
+    path: PathBuf,
+}
+
+/// Contents of a `yun.lock`: every package of the dependency graph, the
+/// root included, with the version found in its manifest. Once locked, a
+/// package directory must keep its name and version.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Lockfile {
+    package: Vec<Locked>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Locked {
+    name: String,
+    version: String,
+    path: String,
+    dependencies: Vec<String>,
+}
+
+/// A directory with a `yun.toml`.
+#[derive(Debug, Clone)]
+pub struct Package {
+    dir: PathBuf,
+    manifest: Manifest,
+}
+
+impl Package {
+    /// Nearest manifest in `dir` or one of its ancestors.
+    pub fn find(dir: &Path) -> Option<PathBuf> {
+        dir.ancestors()
+            .map(|dir| dir.join(MANIFEST))
+            .find(|manifest| manifest.is_file())
+    }
+
+    pub fn load(manifest: &Path) -> Result<Self, PackageError> {
+        let display = manifest.display().to_string();
+        let code = fs::read_to_string(manifest)
+            .map_err(|err| PackageError::ManifestReadFailed(display.clone(), err.to_string()))?;
+        let manifest_ = toml::from_str(&code)
+            .map_err(|err| PackageError::ManifestInvalid(display, err.to_string()))?;
+        let dir = manifest.parent().unwrap_or(Path::new("."));
+        Ok(Self {
+            dir: dir.canonicalize().unwrap_or(dir.to_path_buf()),
+            manifest: manifest_,
+        })
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.manifest.package.name
+    }
+
+    pub fn get_version(&self) -> &str {
+        &self.manifest.package.version
+    }
+
+    pub fn get_dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// File run for the package and imported by `use "name";`.
+    pub fn entry(&self) -> PathBuf {
+        self.dir.join(&self.manifest.package.entry)
+    }
+
+    /// Loads the package a dependency of this one points to.
+    pub fn dependency(&self, name: &str) -> Option<Result<Package, PackageError>> {
+        let dependency = self.manifest.dependencies.get(name)?;
+        let dir = self.dir.join(&dependency.path);
+        let manifest = dir.join(MANIFEST);
+        if !manifest.is_file() {
+            return Some(Err(PackageError::DependencyNotFound(
+                name.to_string(),
+                dir.display().to_string(),
+            )));
+        }
+        Some(Package::load(&manifest))
+    }
+
+    /// Checks the dependency graph against `yun.lock` next to the manifest,
+    /// then writes it with any package that isn't locked yet and without the
+    /// ones no longer depended on.
+    pub fn lock(&self) -> Result<Lockfile, PackageError> {
+        let path = self.dir.join(LOCKFILE);
+        let locked = match fs::read_to_string(&path) {
+            Ok(code) => toml::from_str(&code).map_err(|err| {
+                PackageError::LockInvalid(path.display().to_string(), err.to_string())
+            })?,
+            Err(_) => Lockfile::default(),
+        };
+
+        let mut lockfile = Lockfile::default();
+        let mut seen = HashSet::new();
+        self.collect(&self.dir, &mut lockfile, &mut seen)?;
+        // The root comes first and may change its own version freely.
+        for package in lockfile.package.iter().skip(1) {
+            locked.verify(package)?;
+        }
+        lockfile
+            .package
+            .sort_by(|a, b| a.name.cmp(&b.name).then(a.path.cmp(&b.path)));
+
+        let code = format!(
+            "# Generated by yun, do not edit.\n\n{}",
+            toml::to_string(&lockfile).unwrap_or_default()
+        );
+        if fs::read_to_string(&path).is_ok_and(|locked| locked == code) {
+            return Ok(lockfile);
+        }
+        fs::write(&path, code).map_err(|err| {
+            PackageError::LockWriteFailed(path.display().to_string(), err.to_string())
+        })?;
+        Ok(lockfile)
+    }
+
+    fn locked(&self, root: &Path) -> Locked {
+        Locked {
+            name: self.get_name().to_string(),
+            version: self.get_version().to_string(),
+            path: relative(root, &self.dir).display().to_string(),
+            dependencies: self.manifest.dependencies.keys().cloned().collect(),
+        }
+    }
+
+    /// Adds this package and, once each, the packages it depends on.
+    fn collect(
+        &self,
+        root: &Path,
+        lockfile: &mut Lockfile,
+        seen: &mut HashSet<PathBuf>,
+    ) -> Result<(), PackageError> {
+        if !seen.insert(self.dir.clone()) {
+            return Ok(());
+        }
+        lockfile.package.push(self.locked(root));
+        for name in self.manifest.dependencies.keys() {
+            if let Some(dependency) = self.dependency(name) {
+                dependency?.collect(root, lockfile, seen)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Lockfile {
+    /// Rejects `package`, a dependency of the package at `root`, if the
+    /// lockfile records another name or version for its directory.
+    pub fn check(&self, root: &Path, package: &Package) -> Result<(), PackageError> {
+        self.verify(&package.locked(root))
+    }
+
+    fn verify(&self, package: &Locked) -> Result<(), PackageError> {
+        let Some(locked) = self
+            .package
+            .iter()
+            .find(|locked| locked.path == package.path)
+        else {
+            return Ok(());
+        };
+        if locked.name != package.name || locked.version != package.version {
+            return Err(PackageError::LockMismatch(
+                package.path.clone(),
+                format!("{} {}", locked.name, locked.version),
+                format!("{} {}", package.name, package.version),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// `to` relative to `from`, both absolute.
+fn relative(from: &Path, to: &Path) -> PathBuf {
+    let from = from.components().collect::<Vec<_>>();
+    let to = to.components().collect::<Vec<_>>();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    let mut path = PathBuf::new();
+    for _ in common..from.len() {
+        path.push(Component::ParentDir);
+    }
+    for component in &to[common..] {
+        path.push(component);
+    }
+    if path.as_os_str().is_empty() {
+        path.push(Component::CurDir);
+    }
+    path
+}
@@ -1,6 +1,6 @@
+use crate::interpreter::Interpreter;
 use crate::interpreter::error::InterpreterError;
 use crate::interpreter::scanner::token::Token;
-use crate::interpreter::Interpreter;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Clone)]
@@ -68,6 +68,30 @@ pub enum ParserErrorType {
     CantUseSuperOutsideOfClass,
     CantUseSuperInClassWithoutSuperClasses,
     ExpectedRightBracket,
+    ExpectedIndex,
+    YieldOutsideFunction,
+    CantYieldFromInitializer,
+    SuspendMustBeStatement(String),
+    ExpectedFunAfterAsync,
+    ExpectedFunAfterDecorator,
+    ExpectedFrom,
+    ExpectedImportName,
+    ExpectedModuleAlias,
+    AwaitOutsideAsync,
+    YieldInAsync,
+    ExpectedIdentAfterStructDecl,
+    ExpectedFieldName,
+    StructCantDefineInit,
+    PositionalArgAfterNamedArg,
+    ExpectedIdentAfterEnumDecl,
+    ExpectedVariantName,
+    DuplicateVariant,
+    ReservedPayloadField,
+    PrivateMemberAccess(String),
+    ExpectedConstInitializer,
+    CantAssignToConstant(String),
+    ExpectedPatternInitializer,
+    ExpectedInAfterForBinding,
 }
 
 impl Display for ParserErrorType {
@@ -138,9 +162,74 @@ impl Display for ParserErrorType {
             ParserErrorType::CantInheritItSelf => write!(f, "A class can't inherit from itself!"),
             ParserErrorType::ExpectedMethodAfterDot => write!(f, "Expected method after '.'!"),
             ParserErrorType::ExpectedDotAfterSuper => write!(f, "Expected '.'!"),
-            ParserErrorType::CantUseSuperOutsideOfClass => write!(f, "Can't use 'super' outside of a class!"),
-            ParserErrorType::CantUseSuperInClassWithoutSuperClasses => write!(f, "Can't use 'super' in class without superclasses!"),
+            ParserErrorType::CantUseSuperOutsideOfClass => {
+                write!(f, "Can't use 'super' outside of a class!")
+            }
+            ParserErrorType::CantUseSuperInClassWithoutSuperClasses => {
+                write!(f, "Can't use 'super' in class without superclasses!")
+            }
             ParserErrorType::ExpectedRightBracket => write!(f, "Expected ']'!"),
+            ParserErrorType::ExpectedIndex => write!(f, "Expected index or slice inside '[]'!"),
+            ParserErrorType::YieldOutsideFunction => write!(f, "Can't yield from top-level code!"),
+            ParserErrorType::CantYieldFromInitializer => {
+                write!(f, "Can't yield from an initializer!")
+            }
+            ParserErrorType::SuspendMustBeStatement(keyword) => write!(
+                f,
+                "'{}' can only be a statement or the value of 'let', 'return', 'print' or an assignment!",
+                keyword
+            ),
+            ParserErrorType::ExpectedFunAfterAsync => write!(f, "Expected 'fun' after 'async'!"),
+            ParserErrorType::ExpectedFrom => write!(f, "Expected 'from' before module path!"),
+            ParserErrorType::ExpectedImportName => write!(f, "Expected name to import!"),
+            ParserErrorType::ExpectedModuleAlias => {
+                write!(f, "Expected module name, name the module with 'as'!")
+            }
+            ParserErrorType::ExpectedFunAfterDecorator => {
+                write!(f, "Expected function declaration after decorator!")
+            }
+            ParserErrorType::AwaitOutsideAsync => write!(
+                f,
+                "'await' is only allowed in async functions and top-level code!"
+            ),
+            ParserErrorType::YieldInAsync => write!(f, "Can't yield inside an async function!"),
+            ParserErrorType::ExpectedIdentAfterStructDecl => {
+                write!(f, "Expected identifier after struct declaration!")
+            }
+            ParserErrorType::ExpectedFieldName => write!(f, "Expected field name!"),
+            ParserErrorType::StructCantDefineInit => {
+                write!(f, "Struct initializer is generated from its fields!")
+            }
+            ParserErrorType::PositionalArgAfterNamedArg => {
+                write!(f, "Positional argument can't follow a named argument!")
+            }
+            ParserErrorType::ExpectedIdentAfterEnumDecl => {
+                write!(f, "Expected identifier after enum declaration!")
+            }
+            ParserErrorType::ExpectedVariantName => write!(f, "Expected variant name!"),
+            ParserErrorType::DuplicateVariant => write!(f, "Variant is already declared!"),
+            ParserErrorType::ReservedPayloadField => {
+                write!(f, "Payload field can't be named 'variant' or 'payload'!")
+            }
+            ParserErrorType::PrivateMemberAccess(name) => {
+                write!(
+                    f,
+                    "Private member '{}' can't be accessed outside of a class!",
+                    name
+                )
+            }
+            ParserErrorType::ExpectedConstInitializer => {
+                write!(f, "Expected '=' after constant name!")
+            }
+            ParserErrorType::CantAssignToConstant(name) => {
+                write!(f, "Can't assign to constant '{}'!", name)
+            }
+            ParserErrorType::ExpectedPatternInitializer => {
+                write!(f, "Expected '=' after destructuring pattern!")
+            }
+            ParserErrorType::ExpectedInAfterForBinding => {
+                write!(f, "Expected 'in' after for binding!")
+            }
         }
     }
 }
@@ -1,36 +1,46 @@
 use crate::b;
+use crate::interpreter::ast::expr::Expr;
 use crate::interpreter::ast::expr::assignment::Assign;
+use crate::interpreter::ast::expr::await_expr::Await;
 use crate::interpreter::ast::expr::binary::Binary;
 use crate::interpreter::ast::expr::call::Call;
+use crate::interpreter::ast::expr::comprehension::{Clause, Comprehension};
 use crate::interpreter::ast::expr::get::Get;
 use crate::interpreter::ast::expr::grouping::Grouping;
+use crate::interpreter::ast::expr::index::Index;
+use crate::interpreter::ast::expr::list::List;
 use crate::interpreter::ast::expr::literal::Literal;
 use crate::interpreter::ast::expr::logical::Logical;
+use crate::interpreter::ast::expr::range::Range;
 use crate::interpreter::ast::expr::self_expr::SelfExpr;
 use crate::interpreter::ast::expr::set::Set;
 use crate::interpreter::ast::expr::superclass::Super;
 use crate::interpreter::ast::expr::unary::Unary;
 use crate::interpreter::ast::expr::variable::Variable;
-use crate::interpreter::ast::expr::Expr;
+use crate::interpreter::ast::expr::yield_expr::Yield;
+use crate::interpreter::ast::pattern::Pattern;
+use crate::interpreter::ast::stmt::Stmt;
 use crate::interpreter::ast::stmt::block::Block;
 use crate::interpreter::ast::stmt::class::Class;
+use crate::interpreter::ast::stmt::destructure::Destructure;
+use crate::interpreter::ast::stmt::enum_stmt::Enum;
 use crate::interpreter::ast::stmt::export_stmt::Export;
+use crate::interpreter::ast::stmt::for_in::ForIn;
 use crate::interpreter::ast::stmt::fun_stmt::Fun;
 use crate::interpreter::ast::stmt::if_stmt::If;
 use crate::interpreter::ast::stmt::let_stmt::Let;
 use crate::interpreter::ast::stmt::print::Print;
 use crate::interpreter::ast::stmt::return_stmt::Return;
 use crate::interpreter::ast::stmt::stmt_expr::StmtExpr;
-use crate::interpreter::ast::stmt::use_stmt::Use;
+use crate::interpreter::ast::stmt::struct_stmt::Struct;
+use crate::interpreter::ast::stmt::use_stmt::{Import, Use};
 use crate::interpreter::ast::stmt::while_stmt::While;
-use crate::interpreter::ast::stmt::Stmt;
 use crate::interpreter::error::Result;
 use crate::interpreter::object::Object;
 use crate::interpreter::parser::error::{ParserError, ParserErrorType};
-use crate::interpreter::scanner::token::token_type::TokenType;
 use crate::interpreter::scanner::token::Token;
+use crate::interpreter::scanner::token::token_type::TokenType;
 use std::marker::PhantomData;
-use crate::interpreter::ast::expr::list::List;
 
 pub mod error;
 pub mod resolver;
@@ -38,6 +48,7 @@ pub struct Parser<T> {
     phantom_data: PhantomData<T>,
     tokens: Vec<Token>,
     current: usize,
+    yields: bool,
 }
 
 impl<T> Parser<T>
@@ -49,6 +60,7 @@ where
             phantom_data: Default::default(),
             tokens,
             current: 0,
+            yields: false,
         }
     }
 
@@ -83,18 +95,105 @@ where
             return self.import();
         }
         if self._match(vec![TokenType::Export]) {
-            return Ok(b!(Export::new(self.previous(), self.declaration()?)));
+            let keyword = self.previous();
+            if self._match(vec![TokenType::Use])
+                || self.check(TokenType::LeftBrace)
+                || self.check(TokenType::Star)
+            {
+                return Ok(b!(Export::new(keyword, self.import()?)));
+            }
+            return Ok(b!(Export::new(keyword, self.declaration()?)));
         }
         self.declaration()
     }
 
     fn import(&mut self) -> Result<Box<dyn Stmt<T>>> {
         let name = self.previous();
+
+        if self._match(vec![TokenType::Star]) {
+            if !self.match_word("from") {
+                return Err(self
+                    .error(self.peek(), ParserErrorType::ExpectedFrom)
+                    .into());
+            }
+            let expr = self.expression()?;
+            self.consume(TokenType::Semicolon, ParserErrorType::ExpectedSemicolon)?;
+            return Ok(b!(Use::new(name, expr, Import::All)));
+        }
+
+        if self._match(vec![TokenType::LeftBrace]) {
+            let mut names = vec![];
+            loop {
+                let name =
+                    self.consume(TokenType::Identifier, ParserErrorType::ExpectedImportName)?;
+                let alias = if self.match_word("as") {
+                    self.consume(TokenType::Identifier, ParserErrorType::ExpectedImportName)?
+                } else {
+                    name.clone()
+                };
+                names.push((name, alias));
+                if !self._match(vec![TokenType::Comma]) || self.check(TokenType::RightBrace) {
+                    break;
+                }
+            }
+            self.consume(
+                TokenType::RightBrace,
+                ParserErrorType::ExpectedMatchingBrace,
+            )?;
+            if !self.match_word("from") {
+                return Err(self
+                    .error(self.peek(), ParserErrorType::ExpectedFrom)
+                    .into());
+            }
+            let expr = self.expression()?;
+            self.consume(TokenType::Semicolon, ParserErrorType::ExpectedSemicolon)?;
+            return Ok(b!(Use::new(name, expr, Import::Names(names))));
+        }
+
         let expr = self.expression()?;
+        let alias = if self.match_word("as") {
+            self.consume(TokenType::Identifier, ParserErrorType::ExpectedModuleAlias)?
+        } else {
+            self.module_alias(&name, expr.as_ref())?
+        };
 
         self.consume(TokenType::Semicolon, ParserErrorType::ExpectedSemicolon)?;
 
-        Ok(b!(Use::new(name, expr)))
+        Ok(b!(Use::new(name, expr, Import::Module(alias))))
+    }
+
+    /// Name a module is bound to without `as`: the last segment of its path,
+    /// `use "lib/math";` binds `math`.
+    fn module_alias(&self, keyword: &Token, path: &dyn Expr<T>) -> Result<Token> {
+        let segment = match path.downcast_ref::<Literal>().and_then(Literal::get_value) {
+            Some(Object::String(path)) => path.rsplit('/').next().unwrap_or_default().to_string(),
+            _ => String::new(),
+        };
+        let valid = segment.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid {
+            return Err(
+                ParserError::new(keyword.clone(), ParserErrorType::ExpectedModuleAlias).into(),
+            );
+        }
+        Ok(Token::new(
+            TokenType::Identifier,
+            &segment,
+            None,
+            keyword.get_line(),
+            keyword.get_pos_in_line(),
+        ))
+    }
+
+    /// Matches an identifier used as a contextual keyword, like `as` and `from`.
+    fn match_word(&mut self, word: &str) -> bool {
+        if self.check(TokenType::Identifier) && self.peek().get_lexeme() == word {
+            self.advance();
+            return true;
+        }
+        false
     }
 
     fn declaration(&mut self) -> Result<Box<dyn Stmt<T>>> {
@@ -102,17 +201,160 @@ where
             return self.let_declaration();
         }
 
+        if self._match(vec![TokenType::Const]) {
+            return self.const_declaration();
+        }
+
         if self._match(vec![TokenType::Fun]) {
-            return self.fun_declaration();
+            return self.fun_declaration(false, vec![]);
+        }
+
+        if self._match(vec![TokenType::Async]) {
+            self.consume(TokenType::Fun, ParserErrorType::ExpectedFunAfterAsync)?;
+            return self.fun_declaration(true, vec![]);
+        }
+
+        if self.check(TokenType::At) {
+            let decorators = self.decorators()?;
+            let is_async = self._match(vec![TokenType::Async]);
+            self.consume(TokenType::Fun, ParserErrorType::ExpectedFunAfterDecorator)?;
+            return self.fun_declaration(is_async, decorators);
         }
 
         if self._match(vec![TokenType::Class]) {
             return self.class_declaration();
         }
 
+        if self._match(vec![TokenType::Struct]) {
+            return self.struct_declaration();
+        }
+
+        if self._match(vec![TokenType::Enum]) {
+            return self.enum_declaration();
+        }
+
         self.statement()
     }
 
+    fn enum_declaration(&mut self) -> Result<Box<dyn Stmt<T>>> {
+        let name = self.consume(
+            TokenType::Identifier,
+            ParserErrorType::ExpectedIdentAfterEnumDecl,
+        )?;
+
+        self.consume(
+            TokenType::LeftBrace,
+            ParserErrorType::ExpectedLeftBraceBeforeBody,
+        )?;
+
+        let mut variants: Vec<(Token, Vec<Token>)> = vec![];
+        while !self.check(TokenType::RightBrace) && !self.is_at_end() {
+            let variant =
+                self.consume(TokenType::Identifier, ParserErrorType::ExpectedVariantName)?;
+            if variants
+                .iter()
+                .any(|(other, _)| other.get_lexeme() == variant.get_lexeme())
+            {
+                return Err(ParserError::new(variant, ParserErrorType::DuplicateVariant).into());
+            }
+
+            let mut payload = vec![];
+            if self._match(vec![TokenType::LeftParen]) {
+                loop {
+                    let field =
+                        self.consume(TokenType::Identifier, ParserErrorType::ExpectedParamName)?;
+                    // `variant` and `payload` are read from the value itself.
+                    if matches!(field.get_lexeme(), "variant" | "payload") {
+                        return Err(
+                            ParserError::new(field, ParserErrorType::ReservedPayloadField).into(),
+                        );
+                    }
+                    payload.push(field);
+                    if !self._match(vec![TokenType::Comma]) {
+                        break;
+                    }
+                }
+                self.consume(
+                    TokenType::RightParen,
+                    ParserErrorType::ExpectedRightParenAfterParams,
+                )?;
+            }
+            variants.push((variant, payload));
+
+            if !self._match(vec![TokenType::Comma]) {
+                break;
+            }
+        }
+
+        self.consume(
+            TokenType::RightBrace,
+            ParserErrorType::ExpectedMatchingBrace,
+        )?;
+
+        Ok(b!(Enum::new(name, variants)))
+    }
+
+    fn struct_declaration(&mut self) -> Result<Box<dyn Stmt<T>>> {
+        let name = self.consume(
+            TokenType::Identifier,
+            ParserErrorType::ExpectedIdentAfterStructDecl,
+        )?;
+
+        self.consume(
+            TokenType::LeftBrace,
+            ParserErrorType::ExpectedLeftBraceBeforeBody,
+        )?;
+
+        let mut fields = vec![];
+        let mut methods = vec![];
+        while !self.check(TokenType::RightBrace) && !self.is_at_end() {
+            if self.check(TokenType::Async)
+                || (self.check(TokenType::Identifier) && self.check_next(TokenType::LeftParen))
+            {
+                let is_async = self._match(vec![TokenType::Async]);
+                match self.fun_declaration(is_async, vec![])?.downcast::<Fun<T>>() {
+                    Ok(func) => {
+                        if func.get_name().get_lexeme().eq("init") {
+                            return Err(ParserError::new(
+                                func.get_name(),
+                                ParserErrorType::StructCantDefineInit,
+                            )
+                            .into());
+                        }
+                        methods.push(*func)
+                    }
+                    Err(_) => {
+                        return Err(
+                            ParserError::new(self.previous(), ParserErrorType::NotAFunc).into()
+                        );
+                    }
+                }
+                continue;
+            }
+
+            let field = self.consume(TokenType::Identifier, ParserErrorType::ExpectedFieldName)?;
+            let mut default = None;
+            if self._match(vec![TokenType::Equal]) {
+                default = Some(self.expression()?);
+            }
+            fields.push((field, default));
+
+            if !self._match(vec![TokenType::Comma, TokenType::Semicolon])
+                && !self.check_next(TokenType::LeftParen)
+                && !self.check(TokenType::Async)
+            {
+                break;
+            }
+        }
+
+        self.consume(
+            TokenType::RightBrace,
+            ParserErrorType::ExpectedMatchingBrace,
+        )?;
+
+        Ok(b!(Struct::new(name, fields, methods)))
+    }
+
     fn class_declaration(&mut self) -> Result<Box<dyn Stmt<T>>> {
         let name = self.consume(
             TokenType::Identifier,
@@ -135,7 +377,12 @@ where
 
         let mut methods = vec![];
         while !self.check(TokenType::RightBrace) && !self.is_at_end() {
-            match self.fun_declaration()?.downcast::<Fun<T>>() {
+            let decorators = self.decorators()?;
+            let is_async = self._match(vec![TokenType::Async]);
+            match self
+                .fun_declaration(is_async, decorators)?
+                .downcast::<Fun<T>>()
+            {
                 Ok(func) => methods.push(*func),
                 Err(_) => {
                     return Err(ParserError::new(self.previous(), ParserErrorType::NotAFunc).into());
@@ -151,7 +398,20 @@ where
         Ok(b!(Class::new(name, methods, super_class)))
     }
 
-    fn fun_declaration(&mut self) -> Result<Box<dyn Stmt<T>>> {
+    /// Parses the `@decorator` annotations in front of a function declaration.
+    fn decorators(&mut self) -> Result<Vec<Box<dyn Expr<T>>>> {
+        let mut decorators = vec![];
+        while self._match(vec![TokenType::At]) {
+            decorators.push(self.call()?);
+        }
+        Ok(decorators)
+    }
+
+    fn fun_declaration(
+        &mut self,
+        is_async: bool,
+        decorators: Vec<Box<dyn Expr<T>>>,
+    ) -> Result<Box<dyn Stmt<T>>> {
         let name = self.consume(
             TokenType::Identifier,
             ParserErrorType::ExpectedIdentAfterFunDecl,
@@ -163,10 +423,11 @@ where
         )?;
 
         let mut params = vec![];
+        let mut destructured = vec![];
 
         if !self.check(TokenType::RightParen) {
-            params.push(self.consume(TokenType::Identifier, ParserErrorType::ExpectedParamName)?);
-            while self.check(TokenType::Comma) {
+            params.push(self.param(&mut destructured)?);
+            while self._match(vec![TokenType::Comma]) {
                 if params.len() >= 255 {
                     return Err(ParserError::new(
                         self.peek(),
@@ -174,8 +435,7 @@ where
                     )
                     .into());
                 }
-                params
-                    .push(self.consume(TokenType::Identifier, ParserErrorType::ExpectedParamName)?);
+                params.push(self.param(&mut destructured)?);
             }
         }
 
@@ -188,11 +448,109 @@ where
             ParserErrorType::ExpectedLeftBraceBeforeBody,
         )?;
 
-        let body = self.block_statement()?;
-        Ok(b!(Fun::new(name, params, body)))
+        let enclosing = std::mem::replace(&mut self.yields, false);
+        let body = self.block_statement();
+        let generator = std::mem::replace(&mut self.yields, enclosing);
+        destructured.extend(body?);
+        Ok(b!(Fun::new(name, params, destructured)
+            .with_generator(generator)
+            .with_async(is_async)
+            .with_decorators(decorators)))
+    }
+
+    /// Parses a parameter. A pattern parameter gets a hidden name and is
+    /// unpacked by a `Destructure` pushed to `destructured`.
+    fn param(&mut self, destructured: &mut Vec<Box<dyn Stmt<T>>>) -> Result<Token> {
+        if !self.check(TokenType::LeftBracket) && !self.check(TokenType::LeftBrace) {
+            return self.consume(TokenType::Identifier, ParserErrorType::ExpectedParamName);
+        }
+
+        let pattern = self.pattern()?;
+        let token = pattern.get_token();
+        let name = Token::new(
+            TokenType::Identifier,
+            &format!("@param{}", destructured.len()),
+            None,
+            token.get_line(),
+            token.get_pos_in_line(),
+        );
+        destructured.push(b!(Destructure::new(
+            pattern,
+            b!(Variable::new(name.clone())),
+            false
+        )));
+        Ok(name)
+    }
+
+    fn pattern(&mut self) -> Result<Pattern> {
+        if self._match(vec![TokenType::LeftBracket]) {
+            let token = self.previous();
+            let mut items = vec![];
+            let mut rest = None;
+            while !self.check(TokenType::RightBracket) && !self.is_at_end() {
+                if self._match(vec![TokenType::Ellipsis]) {
+                    rest = Some(
+                        self.consume(TokenType::Identifier, ParserErrorType::ExpectedVariableName)?,
+                    );
+                    break;
+                }
+                items.push(self.pattern()?);
+                if !self._match(vec![TokenType::Comma]) {
+                    break;
+                }
+            }
+            self.consume(
+                TokenType::RightBracket,
+                ParserErrorType::ExpectedRightBracket,
+            )?;
+            return Ok(Pattern::List(token, items, rest));
+        }
+
+        if self._match(vec![TokenType::LeftBrace]) {
+            let token = self.previous();
+            let mut fields = vec![];
+            while !self.check(TokenType::RightBrace) && !self.is_at_end() {
+                fields
+                    .push(self.consume(TokenType::Identifier, ParserErrorType::ExpectedFieldName)?);
+                if !self._match(vec![TokenType::Comma]) {
+                    break;
+                }
+            }
+            self.consume(
+                TokenType::RightBrace,
+                ParserErrorType::ExpectedMatchingBrace,
+            )?;
+            return Ok(Pattern::Fields(token, fields));
+        }
+
+        Ok(Pattern::Name(self.consume(
+            TokenType::Identifier,
+            ParserErrorType::ExpectedVariableName,
+        )?))
+    }
+
+    fn destructure_declaration(&mut self, constant: bool) -> Result<Box<dyn Stmt<T>>> {
+        let pattern = self.pattern()?;
+
+        self.consume(
+            TokenType::Equal,
+            ParserErrorType::ExpectedPatternInitializer,
+        )?;
+        let initializer = self.expression()?;
+
+        self.consume(
+            TokenType::Semicolon,
+            ParserErrorType::ExpectedSemicolonAfterVarDecl,
+        )?;
+
+        Ok(b!(Destructure::new(pattern, initializer, constant)))
     }
 
     fn let_declaration(&mut self) -> Result<Box<dyn Stmt<T>>> {
+        if self.check(TokenType::LeftBracket) || self.check(TokenType::LeftBrace) {
+            return self.destructure_declaration(false);
+        }
+
         let name = self.consume(TokenType::Identifier, ParserErrorType::ExpectedVariableName)?;
 
         let mut initializer = None;
@@ -208,6 +566,24 @@ where
         Ok(b!(Let::new(name, initializer)))
     }
 
+    fn const_declaration(&mut self) -> Result<Box<dyn Stmt<T>>> {
+        if self.check(TokenType::LeftBracket) || self.check(TokenType::LeftBrace) {
+            return self.destructure_declaration(true);
+        }
+
+        let name = self.consume(TokenType::Identifier, ParserErrorType::ExpectedVariableName)?;
+
+        self.consume(TokenType::Equal, ParserErrorType::ExpectedConstInitializer)?;
+        let initializer = self.expression()?;
+
+        self.consume(
+            TokenType::Semicolon,
+            ParserErrorType::ExpectedSemicolonAfterVarDecl,
+        )?;
+
+        Ok(b!(Let::constant(name, initializer)))
+    }
+
     fn statement(&mut self) -> Result<Box<dyn Stmt<T>>> {
         if self._match(vec![TokenType::Print]) {
             return self.print_statement();
@@ -247,11 +623,19 @@ where
     }
 
     fn for_statement(&mut self) -> Result<Box<dyn Stmt<T>>> {
+        let keyword = self.previous();
         self.consume(
             TokenType::LeftParen,
             ParserErrorType::ExpectedLeftParenAfterFor,
         )?;
 
+        if (self.check(TokenType::Identifier) && self.check_next(TokenType::In))
+            || self.check(TokenType::LeftBracket)
+            || self.check(TokenType::LeftBrace)
+        {
+            return self.for_in_statement(keyword);
+        }
+
         let initializer: Option<Box<dyn Stmt<T>>> = if self._match(vec![TokenType::Let]) {
             Some(self.let_declaration()?)
         } else {
@@ -290,6 +674,20 @@ where
         Ok(body)
     }
 
+    fn for_in_statement(&mut self, keyword: Token) -> Result<Box<dyn Stmt<T>>> {
+        let pattern = self.pattern()?;
+        self.consume(TokenType::In, ParserErrorType::ExpectedInAfterForBinding)?;
+        let iterable = self.expression()?;
+        self.consume(
+            TokenType::RightParen,
+            ParserErrorType::ExpectedRightParenAfterForStatement,
+        )?;
+
+        let body = self.statement()?;
+
+        Ok(b!(ForIn::new(keyword, pattern, iterable, body)))
+    }
+
     fn while_statement(&mut self) -> Result<Box<dyn Stmt<T>>> {
         let condition = self.expression()?;
 
@@ -342,7 +740,11 @@ where
     }
 
     fn assignment(&mut self) -> Result<Box<dyn Expr<T>>> {
-        let expr = self.logic_or()?;
+        if self._match(vec![TokenType::Yield]) {
+            return self.yield_expr();
+        }
+
+        let expr = self.pipeline()?;
         if self._match(vec![TokenType::Equal]) {
             let token = self.previous();
             let value = self.assignment()?;
@@ -363,6 +765,30 @@ where
         Ok(expr)
     }
 
+    fn yield_expr(&mut self) -> Result<Box<dyn Expr<T>>> {
+        let keyword = self.previous();
+        self.yields = true;
+        let value = if self.check(TokenType::Semicolon) || self.check(TokenType::RightParen) {
+            None
+        } else {
+            Some(self.assignment()?)
+        };
+        Ok(b!(Yield::new(keyword, value)))
+    }
+
+    /// `x |> f` becomes `f(x)` and `x |> f(a)` becomes `f(x, a)`.
+    fn pipeline(&mut self) -> Result<Box<dyn Expr<T>>> {
+        let mut expr = self.logic_or()?;
+        while self._match(vec![TokenType::Pipe]) {
+            let pipe = self.previous();
+            expr = match self.call()?.downcast::<Call<T>>() {
+                Ok(call) => b!(call.with_first_arg(expr)),
+                Err(callable) => b!(Call::new(callable, pipe, vec![expr])),
+            };
+        }
+        Ok(expr)
+    }
+
     fn logic_or(&mut self) -> Result<Box<dyn Expr<T>>> {
         let mut expr = self.logic_and()?;
 
@@ -400,22 +826,35 @@ where
     }
 
     fn comparison(&mut self) -> Result<Box<dyn Expr<T>>> {
-        let mut expr = self.term()?;
+        let mut expr = self.range()?;
 
         while self._match(vec![
             TokenType::Greater,
             TokenType::GreaterEqual,
             TokenType::Less,
             TokenType::LessEqual,
+            TokenType::In,
         ]) {
             let token = self.previous();
-            let right = self.term()?;
+            let right = self.range()?;
             expr = b!(Binary::new(expr, token, right));
         }
 
         Ok(expr)
     }
 
+    fn range(&mut self) -> Result<Box<dyn Expr<T>>> {
+        let expr = self.term()?;
+
+        if self._match(vec![TokenType::DotDot, TokenType::DotDotEqual]) {
+            let token = self.previous();
+            let end = self.term()?;
+            return Ok(b!(Range::new(expr, token, end)));
+        }
+
+        Ok(expr)
+    }
+
     fn term(&mut self) -> Result<Box<dyn Expr<T>>> {
         let mut expr = self.factor()?;
 
@@ -447,6 +886,12 @@ where
             return Ok(b!(Unary::new(token, right)));
         }
 
+        if self._match(vec![TokenType::Await]) {
+            let keyword = self.previous();
+            let value = self.unary()?;
+            return Ok(b!(Await::new(keyword, value)));
+        }
+
         self.call()
     }
 
@@ -461,6 +906,8 @@ where
                     ParserErrorType::ExpectedPropertyAfterDot,
                 )?;
                 expr = b!(Get::new(name, expr))
+            } else if self._match(vec![TokenType::LeftBracket]) {
+                expr = self.finish_index(expr)?;
             } else {
                 break;
             }
@@ -469,12 +916,62 @@ where
         Ok(expr)
     }
 
+    fn finish_index(&mut self, expr: Box<dyn Expr<T>>) -> Result<Box<dyn Expr<T>>> {
+        let bracket = self.previous();
+        let mut bounds = vec![self.slice_bound()?];
+        while bounds.len() < 3 && self._match(vec![TokenType::Colon]) {
+            bounds.push(self.slice_bound()?);
+        }
+        self.consume(
+            TokenType::RightBracket,
+            ParserErrorType::ExpectedRightBracket,
+        )?;
+
+        if bounds.len() == 1 {
+            return match bounds.pop().flatten() {
+                Some(index) => Ok(b!(Index::new(expr, bracket, index))),
+                None => Err(ParserError::new(bracket, ParserErrorType::ExpectedIndex).into()),
+            };
+        }
+        let mut bounds = bounds.into_iter();
+        Ok(b!(Index::slice(
+            expr,
+            bracket,
+            bounds.next().flatten(),
+            bounds.next().flatten(),
+            bounds.next().flatten(),
+        )))
+    }
+
+    fn slice_bound(&mut self) -> Result<Option<Box<dyn Expr<T>>>> {
+        if self.check(TokenType::Colon) || self.check(TokenType::RightBracket) {
+            return Ok(None);
+        }
+        Ok(Some(self.expression()?))
+    }
+
     fn finish_call(&mut self, expr: Box<dyn Expr<T>>) -> Result<Box<dyn Expr<T>>> {
         let mut arguments = vec![];
+        let mut named_arguments = vec![];
         if !self.check(TokenType::RightParen) {
-            arguments.push(self.expression()?);
-            while self._match(vec![TokenType::Comma]) {
-                arguments.push(self.expression()?);
+            loop {
+                if self.check(TokenType::Identifier) && self.check_next(TokenType::Colon) {
+                    let name = self.advance();
+                    self.advance();
+                    named_arguments.push((name, self.expression()?));
+                } else if named_arguments.is_empty() {
+                    arguments.push(self.expression()?);
+                } else {
+                    return Err(ParserError::new(
+                        self.peek(),
+                        ParserErrorType::PositionalArgAfterNamedArg,
+                    )
+                    .into());
+                }
+
+                if !self._match(vec![TokenType::Comma]) {
+                    break;
+                }
             }
         }
 
@@ -483,11 +980,13 @@ where
             ParserErrorType::ExpectedRightParenAfterArguments,
         )?;
 
-        if arguments.len() > 255 {
+        if arguments.len() + named_arguments.len() > 255 {
             return Err(ParserError::new(paren, ParserErrorType::CountOfArgsGreaterThen255).into());
         }
 
-        Ok(b!(Call::new(expr, paren, arguments)))
+        Ok(b!(
+            Call::new(expr, paren, arguments).with_named_args(named_arguments)
+        ))
     }
 
     fn primary(&mut self) -> Result<Box<dyn Expr<T>>> {
@@ -532,9 +1031,9 @@ where
 
             return Ok(b!(Super::new(keyword, method)));
         }
-        
+
         if self._match(vec![TokenType::LeftBracket]) {
-            return self.list()
+            return self.list();
         }
 
         Err(self
@@ -546,14 +1045,42 @@ where
         let mut values = vec![];
         if !self.check(TokenType::RightBracket) {
             values.push(self.expression()?);
+            if self.check(TokenType::For) {
+                let element = values.pop().unwrap();
+                return self.comprehension(element);
+            }
             while self._match(vec![TokenType::Comma]) {
                 values.push(self.expression()?);
             }
         }
-        self.consume(TokenType::RightBracket, ParserErrorType::ExpectedRightBracket)?;
+        self.consume(
+            TokenType::RightBracket,
+            ParserErrorType::ExpectedRightBracket,
+        )?;
         Ok(b!(List::new(values)))
     }
-    
+
+    fn comprehension(&mut self, element: Box<dyn Expr<T>>) -> Result<Box<dyn Expr<T>>> {
+        let mut clauses = vec![];
+        loop {
+            if self._match(vec![TokenType::For]) {
+                let keyword = self.previous();
+                let pattern = self.pattern()?;
+                self.consume(TokenType::In, ParserErrorType::ExpectedInAfterForBinding)?;
+                clauses.push(Clause::For(keyword, pattern, self.expression()?));
+            } else if self._match(vec![TokenType::If]) {
+                clauses.push(Clause::If(self.expression()?));
+            } else {
+                break;
+            }
+        }
+        self.consume(
+            TokenType::RightBracket,
+            ParserErrorType::ExpectedRightBracket,
+        )?;
+        Ok(b!(Comprehension::new(element, clauses)))
+    }
+
     fn _match(&mut self, types: Vec<TokenType>) -> bool {
         for ty in types {
             if self.check(ty) {
@@ -578,6 +1105,13 @@ where
         self.peek().get_type().eq(&ty)
     }
 
+    fn check_next(&self, ty: TokenType) -> bool {
+        match self.tokens.get(self.current + 1) {
+            Some(token) => token.get_type().eq(&ty),
+            None => false,
+        }
+    }
+
     fn advance(&mut self) -> Token {
         if !self.is_at_end() {
             self.current += 1;
@@ -610,8 +1144,11 @@ where
 
             match self.peek().get_type() {
                 TokenType::Class
+                | TokenType::Struct
+                | TokenType::Enum
                 | TokenType::Fun
                 | TokenType::Let
+                | TokenType::Const
                 | TokenType::For
                 | TokenType::If
                 | TokenType::While
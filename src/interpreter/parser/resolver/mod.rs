@@ -1,35 +1,48 @@
+use crate::interpreter::Interpreter;
 use crate::interpreter::ast::expr::assignment::Assign;
+use crate::interpreter::ast::expr::await_expr::Await;
 use crate::interpreter::ast::expr::binary::Binary;
 use crate::interpreter::ast::expr::call::Call;
+use crate::interpreter::ast::expr::comprehension::{Clause, Comprehension};
 use crate::interpreter::ast::expr::get::Get;
 use crate::interpreter::ast::expr::grouping::Grouping;
+use crate::interpreter::ast::expr::index::Index;
+use crate::interpreter::ast::expr::list::List;
 use crate::interpreter::ast::expr::literal::Literal;
 use crate::interpreter::ast::expr::logical::Logical;
+use crate::interpreter::ast::expr::range::Range;
 use crate::interpreter::ast::expr::self_expr::SelfExpr;
 use crate::interpreter::ast::expr::set::Set;
 use crate::interpreter::ast::expr::superclass::Super;
 use crate::interpreter::ast::expr::unary::Unary;
 use crate::interpreter::ast::expr::variable::Variable;
+use crate::interpreter::ast::expr::yield_expr::Yield;
 use crate::interpreter::ast::expr::{Expr, ExprVisitor};
 use crate::interpreter::ast::stmt::block::Block;
 use crate::interpreter::ast::stmt::class::Class;
+use crate::interpreter::ast::stmt::destructure::Destructure;
+use crate::interpreter::ast::stmt::enum_stmt::Enum;
 use crate::interpreter::ast::stmt::export_stmt::Export;
+use crate::interpreter::ast::stmt::for_in::ForIn;
 use crate::interpreter::ast::stmt::fun_stmt::Fun;
 use crate::interpreter::ast::stmt::if_stmt::If;
 use crate::interpreter::ast::stmt::let_stmt::Let;
 use crate::interpreter::ast::stmt::print::Print;
 use crate::interpreter::ast::stmt::return_stmt::Return;
 use crate::interpreter::ast::stmt::stmt_expr::StmtExpr;
-use crate::interpreter::ast::stmt::use_stmt::Use;
+use crate::interpreter::ast::stmt::struct_stmt::Struct;
+use crate::interpreter::ast::stmt::use_stmt::{Import, ModuleAst, Use};
 use crate::interpreter::ast::stmt::while_stmt::While;
-use crate::interpreter::ast::stmt::{Stmt, StmtVisitor};
+use crate::interpreter::ast::stmt::{Stmt, StmtVisitor, declared_names};
+use crate::interpreter::coroutine::Coroutine;
 use crate::interpreter::error::Result;
+use crate::interpreter::exporter::Exporter;
 use crate::interpreter::object::Object;
+use crate::interpreter::object::instance::Instance;
 use crate::interpreter::parser::error::{ParserError, ParserErrorType};
 use crate::interpreter::scanner::token::Token;
-use crate::interpreter::Interpreter;
-use std::collections::HashMap;
-use crate::interpreter::ast::expr::list::List;
+use crate::interpreter::stdlib;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum FunctionType {
@@ -49,8 +62,12 @@ pub enum ClassType {
 pub struct Resolver<'a> {
     interpreter: &'a mut Interpreter,
     stack: Vec<HashMap<String, bool>>,
+    constants: Vec<HashSet<String>>,
+    global_constants: HashSet<String>,
     current_function: FunctionType,
     current_class: ClassType,
+    current_async: bool,
+    statement_suspend: Option<u64>,
 }
 
 impl<'a> Resolver<'a>
@@ -61,8 +78,12 @@ where
         Self {
             interpreter,
             stack: vec![],
+            constants: vec![],
+            global_constants: HashSet::new(),
             current_function: FunctionType::None,
             current_class: ClassType::None,
+            current_async: false,
+            statement_suspend: None,
         }
     }
 
@@ -83,10 +104,39 @@ where
 
     fn begin_scope(&mut self) {
         self.stack.push(HashMap::new());
+        self.constants.push(HashSet::new());
     }
 
     fn end_scope(&mut self) {
         self.stack.pop();
+        self.constants.pop();
+    }
+
+    fn define_const(&mut self, name: &Token) {
+        match self.constants.last_mut() {
+            Some(scope) => scope.insert(name.get_lexeme().to_string()),
+            None => self.global_constants.insert(name.get_lexeme().to_string()),
+        };
+    }
+
+    fn check_assignable(&self, name: &Token) -> Result<()> {
+        let lexeme = name.get_lexeme();
+        let constant = match self
+            .stack
+            .iter()
+            .rposition(|scope| scope.contains_key(lexeme))
+        {
+            Some(i) => self.constants[i].contains(lexeme),
+            None => self.global_constants.contains(lexeme),
+        };
+        if constant {
+            return Err(ParserError::new(
+                name.clone(),
+                ParserErrorType::CantAssignToConstant(lexeme.to_string()),
+            )
+            .into());
+        }
+        Ok(())
     }
 
     fn declare(&mut self, name: &Token) {
@@ -96,6 +146,7 @@ where
 
         let scope = self.stack.last_mut().unwrap();
         scope.insert(name.get_lexeme().to_string(), false);
+        self.constants.last_mut().unwrap().remove(name.get_lexeme());
     }
 
     fn define(&mut self, name: &Token) {
@@ -121,9 +172,71 @@ where
         }
     }
 
+    fn check_private_access(&self, name: &Token) -> Result<()> {
+        if self.current_class == ClassType::None && Instance::is_private_member(name.get_lexeme()) {
+            return Err(ParserError::new(
+                name.clone(),
+                ParserErrorType::PrivateMemberAccess(name.get_lexeme().to_string()),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Allows the `yield` or `await` of a statement that can suspend by itself.
+    fn allow_suspend(&mut self, stmt: &dyn Stmt<Result<Object>>) {
+        self.statement_suspend = Coroutine::suspend_point(stmt).map(|expr| expr.id());
+    }
+
+    fn check_suspend_position(&mut self, keyword: &Token, id: u64) -> Result<()> {
+        if self.statement_suspend.take() != Some(id) {
+            return Err(ParserError::new(
+                keyword.clone(),
+                ParserErrorType::SuspendMustBeStatement(keyword.get_lexeme().to_string()),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Resolves a module body in its own scope and returns the constants it
+    /// declares. Top-level declarations are defined up front, so functions may
+    /// refer to ones declared after them like they can at the top level of a
+    /// script, and so are the `natives` of a standard library module.
+    pub fn resolve_module(
+        &mut self,
+        module: &ModuleAst<Result<Object>>,
+    ) -> Result<HashSet<String>> {
+        let stmts = module
+            .get_body()
+            .iter()
+            .map(AsRef::as_ref)
+            .collect::<Vec<_>>();
+        let stack = std::mem::take(&mut self.stack);
+        let constants = std::mem::take(&mut self.constants);
+        self.begin_scope();
+        for (native, _) in stdlib::natives(module.get_path()) {
+            self.stack
+                .last_mut()
+                .unwrap()
+                .insert(native.to_string(), true);
+        }
+        for stmt in &stmts {
+            for name in declared_names(*stmt) {
+                self.define(&name);
+            }
+        }
+        let res = self.resolve(stmts);
+        let module_constants = self.constants.pop().unwrap_or_default();
+        self.stack = stack;
+        self.constants = constants;
+        res.map(|_| module_constants)
+    }
+
     fn resolve_function(&mut self, func: &Fun<Result<Object>>, ty: FunctionType) -> Result<()> {
         let enclosing_func = self.current_function;
         self.current_function = ty;
+        let enclosing_async = std::mem::replace(&mut self.current_async, func.is_async());
 
         self.begin_scope();
         let (_, _, params, body) = func.clone().extract();
@@ -135,6 +248,7 @@ where
         self.end_scope();
 
         self.current_function = enclosing_func;
+        self.current_async = enclosing_async;
         Ok(())
     }
 }
@@ -180,6 +294,7 @@ impl ExprVisitor<Result<Object>> for Resolver<'_> {
     }
 
     fn visit_assign(&mut self, assign: &Assign<Result<Object>>) -> Result<Object> {
+        self.check_assignable(&assign.get_token())?;
         self.resolve_expr(assign.get_value())?;
         self.resolve_local(assign, &assign.get_token());
         Ok(Object::Nil)
@@ -196,16 +311,22 @@ impl ExprVisitor<Result<Object>> for Resolver<'_> {
         for arg in call.get_args() {
             self.resolve_expr(arg)?;
         }
+        for (_, arg) in call.get_named_args() {
+            self.resolve_expr(arg)?;
+        }
         Ok(Object::Nil)
     }
 
     fn visit_get(&mut self, get: &Get<Result<Object>>) -> Result<Object> {
-        self.resolve_expr(get.extract().1)?;
+        let (name, obj) = get.extract();
+        self.check_private_access(name)?;
+        self.resolve_expr(obj)?;
         Ok(Object::Nil)
     }
 
     fn visit_set(&mut self, set: &Set<Result<Object>>) -> Result<Object> {
-        let (_, obj, value) = set.extract();
+        let (name, obj, value) = set.extract();
+        self.check_private_access(name)?;
         self.resolve_expr(obj)?;
         self.resolve_expr(value)?;
         Ok(Object::Nil)
@@ -225,9 +346,17 @@ impl ExprVisitor<Result<Object>> for Resolver<'_> {
 
     fn visit_super(&mut self, super_val: &Super) -> Result<Object> {
         if self.current_class == ClassType::None {
-            return Err(ParserError::new(super_val.extract().0, ParserErrorType::CantUseSuperOutsideOfClass).into())
+            return Err(ParserError::new(
+                super_val.extract().0,
+                ParserErrorType::CantUseSuperOutsideOfClass,
+            )
+            .into());
         } else if self.current_class != ClassType::SubClass {
-            return Err(ParserError::new(super_val.extract().0, ParserErrorType::CantUseSuperInClassWithoutSuperClasses).into())
+            return Err(ParserError::new(
+                super_val.extract().0,
+                ParserErrorType::CantUseSuperInClassWithoutSuperClasses,
+            )
+            .into());
         }
         self.resolve_local(super_val, &super_val.extract().0);
         Ok(Object::Nil)
@@ -240,15 +369,99 @@ impl ExprVisitor<Result<Object>> for Resolver<'_> {
         }
         Ok(Object::Nil)
     }
+
+    fn visit_range(&mut self, range: &Range<Result<Object>>) -> Result<Object> {
+        let (start, _, end) = range.extract();
+        self.resolve_expr(start)?;
+        self.resolve_expr(end)?;
+        Ok(Object::Nil)
+    }
+
+    fn visit_comprehension(
+        &mut self,
+        comprehension: &Comprehension<Result<Object>>,
+    ) -> Result<Object> {
+        let mut scopes = 0;
+        for clause in comprehension.get_clauses() {
+            match clause {
+                Clause::For(_, pattern, iterable) => {
+                    self.resolve_expr(iterable.as_ref())?;
+                    self.begin_scope();
+                    scopes += 1;
+                    for name in pattern.names() {
+                        self.declare(&name);
+                        self.define(&name);
+                    }
+                }
+                Clause::If(condition) => {
+                    self.resolve_expr(condition.as_ref())?;
+                }
+            }
+        }
+        let res = self.resolve_expr(comprehension.get_element());
+        for _ in 0..scopes {
+            self.end_scope();
+        }
+        res.map(|_| Object::Nil)
+    }
+
+    fn visit_yield(&mut self, yield_expr: &Yield<Result<Object>>) -> Result<Object> {
+        let keyword = yield_expr.get_keyword().clone();
+        match self.current_function {
+            FunctionType::None => {
+                return Err(
+                    ParserError::new(keyword, ParserErrorType::YieldOutsideFunction).into(),
+                );
+            }
+            FunctionType::Initializer => {
+                return Err(
+                    ParserError::new(keyword, ParserErrorType::CantYieldFromInitializer).into(),
+                );
+            }
+            _ if self.current_async => {
+                return Err(ParserError::new(keyword, ParserErrorType::YieldInAsync).into());
+            }
+            _ => {}
+        }
+        self.check_suspend_position(&keyword, yield_expr.id())?;
+        if let Some(value) = yield_expr.get_value() {
+            self.resolve_expr(value)?;
+        }
+        Ok(Object::Nil)
+    }
+
+    fn visit_await(&mut self, await_expr: &Await<Result<Object>>) -> Result<Object> {
+        let keyword = await_expr.get_keyword();
+        if self.current_function != FunctionType::None {
+            if !self.current_async {
+                return Err(
+                    ParserError::new(keyword.clone(), ParserErrorType::AwaitOutsideAsync).into(),
+                );
+            }
+            self.check_suspend_position(keyword, await_expr.id())?;
+        }
+        self.resolve_expr(await_expr.get_value())?;
+        Ok(Object::Nil)
+    }
+
+    fn visit_index(&mut self, index: &Index<Result<Object>>) -> Result<Object> {
+        self.resolve_expr(index.get_object())?;
+        for bound in index.get_bounds().into_iter().flatten() {
+            self.resolve_expr(bound)?;
+        }
+        Ok(Object::Nil)
+    }
 }
 
 impl StmtVisitor<Result<Object>> for Resolver<'_> {
     fn visit_expr(&mut self, stmt: &StmtExpr<Result<Object>>) -> Result<Object> {
+        self.allow_suspend(stmt);
         self.resolve_expr(stmt.expr())?;
         Ok(Object::Nil)
     }
 
     fn visit_print(&mut self, stmt: &Print<Result<Object>>) -> Result<Object> {
+        self.allow_suspend(stmt);
         self.resolve_expr(stmt.expr())?;
         Ok(Object::Nil)
     }
@@ -256,10 +469,16 @@ impl StmtVisitor<Result<Object>> for Resolver<'_> {
     fn visit_let(&mut self, stmt: &Let<Result<Object>>) -> Result<Object> {
         let name = stmt.get_ident();
         self.declare(&name);
+        self.allow_suspend(stmt);
         if let Some(initializer) = stmt.get_initializer() {
             self.resolve_expr(initializer)?;
         }
         self.define(&name);
+        if stmt.is_const() {
+            self.define_const(&name);
+        } else if self.stack.is_empty() {
+            self.global_constants.remove(name.get_lexeme());
+        }
         Ok(Object::Nil)
     }
 
@@ -289,6 +508,9 @@ impl StmtVisitor<Result<Object>> for Resolver<'_> {
 
     fn visit_fun(&mut self, stmt: &Fun<Result<Object>>) -> Result<Object> {
         let name = stmt.get_name();
+        for decorator in stmt.get_decorators() {
+            self.resolve_expr(decorator.as_ref())?;
+        }
         self.declare(&name);
         self.define(&name);
         self.resolve_function(stmt, FunctionType::Function)?;
@@ -314,6 +536,7 @@ impl StmtVisitor<Result<Object>> for Resolver<'_> {
                 )
                 .into());
             }
+            self.allow_suspend(stmt);
             self.resolve_expr(expr)?;
         }
         Ok(Object::Nil)
@@ -324,8 +547,8 @@ impl StmtVisitor<Result<Object>> for Resolver<'_> {
         let enclosing_ty = self.current_class;
         self.current_class = ClassType::Class;
 
-        self.define(name);
         self.declare(name);
+        self.define(name);
 
         if let Some(super_class) = super_class {
             let s_name = super_class.get_token();
@@ -340,6 +563,12 @@ impl StmtVisitor<Result<Object>> for Resolver<'_> {
             self.stack.last_mut().unwrap().insert("super".into(), true);
         }
 
+        for method in methods {
+            for decorator in method.get_decorators() {
+                self.resolve_expr(decorator.as_ref())?;
+            }
+        }
+
         self.begin_scope();
 
         self.stack.last_mut().unwrap().insert("self".into(), true);
@@ -370,8 +599,101 @@ impl StmtVisitor<Result<Object>> for Resolver<'_> {
         self.resolve_stmt(stmt)
     }
 
-    fn visit_use(&mut self, _stmt: &Use<Result<Object>>) -> Result<Object> {
-        let (_, expr) = _stmt.extract();
-        self.resolve_expr(expr)
+    fn visit_use(&mut self, stmt: &Use<Result<Object>>) -> Result<Object> {
+        let (_, expr) = stmt.extract();
+        self.resolve_expr(expr)?;
+
+        let Some(module) = stmt.get_module() else {
+            return Ok(Object::Nil);
+        };
+        let body = module.get_body();
+        let constants = self.resolve_module(module)?;
+        let names = match stmt.get_import() {
+            Import::All => Exporter::exports(body)
+                .into_iter()
+                .map(|name| (name.clone(), name))
+                .collect(),
+            Import::Names(names) => names.clone(),
+            Import::Module(alias) => {
+                self.declare(alias);
+                self.define(alias);
+                return Ok(Object::Nil);
+            }
+        };
+        for (name, alias) in names {
+            self.declare(&alias);
+            self.define(&alias);
+            if constants.contains(name.get_lexeme()) {
+                self.define_const(&alias);
+            }
+        }
+        Ok(Object::Nil)
+    }
+
+    fn visit_struct(&mut self, stmt: &Struct<Result<Object>>) -> Result<Object> {
+        let (name, fields, methods) = stmt.extract();
+        let enclosing_ty = self.current_class;
+        self.current_class = ClassType::Class;
+
+        self.declare(name);
+        self.define(name);
+
+        for (_, default) in fields {
+            if let Some(default) = default {
+                self.resolve_expr(default.as_ref())?;
+            }
+        }
+
+        self.begin_scope();
+        self.stack.last_mut().unwrap().insert("self".into(), true);
+
+        for method in methods {
+            self.resolve_function(method, FunctionType::Method)?;
+        }
+
+        self.end_scope();
+        self.current_class = enclosing_ty;
+
+        Ok(Object::Nil)
+    }
+
+    fn visit_enum(&mut self, stmt: &Enum) -> Result<Object> {
+        let (name, _) = stmt.extract();
+        self.declare(name);
+        self.define(name);
+        Ok(Object::Nil)
+    }
+
+    fn visit_destructure(&mut self, stmt: &Destructure<Result<Object>>) -> Result<Object> {
+        let (pattern, initializer) = stmt.extract();
+        let names = pattern.names();
+        for name in &names {
+            self.declare(name);
+        }
+        self.allow_suspend(stmt);
+        self.resolve_expr(initializer)?;
+        for name in &names {
+            self.define(name);
+            if stmt.is_const() {
+                self.define_const(name);
+            } else if self.stack.is_empty() {
+                self.global_constants.remove(name.get_lexeme());
+            }
+        }
+        Ok(Object::Nil)
+    }
+
+    fn visit_for_in(&mut self, stmt: &ForIn<Result<Object>>) -> Result<Object> {
+        let (_, pattern, iterable, body) = stmt.extract();
+        self.resolve_expr(iterable)?;
+
+        self.begin_scope();
+        for name in pattern.names() {
+            self.declare(&name);
+            self.define(&name);
+        }
+        self.resolve_stmt(body)?;
+        self.end_scope();
+        Ok(Object::Nil)
     }
 }
@@ -3,14 +3,14 @@ pub mod token;
 use crate::interpreter::error::Result;
 use crate::interpreter::object::Object;
 use crate::interpreter::scanner::error::{ScannerError, ScannerErrorType};
-use crate::interpreter::scanner::token::token_type::TokenType;
 use crate::interpreter::scanner::token::Token;
+use crate::interpreter::scanner::token::token_type::TokenType;
 use std::collections::HashMap;
 
 pub mod error;
 
 pub struct Scanner {
-    source: String,
+    source: Vec<char>,
     tokens: Vec<Token>,
     start: usize,
     current: usize,
@@ -33,15 +33,22 @@ impl Scanner {
 
         keywords.insert("for".into(), TokenType::For);
         keywords.insert("while".into(), TokenType::While);
+        keywords.insert("in".into(), TokenType::In);
 
         keywords.insert("fun".into(), TokenType::Fun);
         keywords.insert("class".into(), TokenType::Class);
+        keywords.insert("struct".into(), TokenType::Struct);
+        keywords.insert("enum".into(), TokenType::Enum);
         keywords.insert("let".into(), TokenType::Let);
+        keywords.insert("const".into(), TokenType::Const);
 
         keywords.insert("nil".into(), TokenType::Nil);
 
         keywords.insert("print".into(), TokenType::Print);
         keywords.insert("return".into(), TokenType::Return);
+        keywords.insert("yield".into(), TokenType::Yield);
+        keywords.insert("async".into(), TokenType::Async);
+        keywords.insert("await".into(), TokenType::Await);
 
         keywords.insert("super".into(), TokenType::Super);
         keywords.insert("self".into(), TokenType::Slf);
@@ -50,7 +57,7 @@ impl Scanner {
         keywords.insert("export".into(), TokenType::Export);
 
         Self {
-            source: source.into(),
+            source: source.chars().collect(),
             tokens: vec![],
             start: 0,
             current: 0,
@@ -89,10 +96,24 @@ impl Scanner {
             '{' => self.add_token(TokenType::LeftBrace, None),
             '}' => self.add_token(TokenType::RightBrace, None),
             ',' => self.add_token(TokenType::Comma, None),
+            '.' if self.peek() == '.' && self.peek_next() == '.' => {
+                self.advance();
+                self.advance();
+                self.add_token(TokenType::Ellipsis, None)
+            }
+            '.' if self.find_match('.') => {
+                if self.find_match('=') {
+                    self.add_token(TokenType::DotDotEqual, None)
+                } else {
+                    self.add_token(TokenType::DotDot, None)
+                }
+            }
             '.' => self.add_token(TokenType::Dot, None),
             '-' => self.add_token(TokenType::Minus, None),
             '+' => self.add_token(TokenType::Plus, None),
             ';' => self.add_token(TokenType::Semicolon, None),
+            ':' => self.add_token(TokenType::Colon, None),
+            '@' => self.add_token(TokenType::At, None),
             '*' => self.add_token(TokenType::Star, None),
             '!' if self.find_match('=') => self.add_token(TokenType::BangEqual, None),
             '!' => self.add_token(TokenType::Bang, None),
@@ -108,6 +129,7 @@ impl Scanner {
                 }
             }
             '/' => self.add_token(TokenType::Slash, None),
+            '|' if self.find_match('>') => self.add_token(TokenType::Pipe, None),
             ' ' | '\r' | '\t' => {}
             '\n' => {
                 self.line += 1;
@@ -133,28 +155,32 @@ impl Scanner {
     }
 
     fn advance(&mut self) -> char {
-        let ch = self.source.chars().nth(self.current).unwrap();
+        let ch = self.source[self.current];
         self.current += 1;
         self.pos_in_line += 1;
         ch
     }
 
     fn add_token(&mut self, ty: TokenType, lit: Option<Object>) {
-        let text = self.source[self.start..self.current].to_string();
+        let text = self.text(self.start, self.current);
         self.tokens.push(Token::new(
             ty,
             &text,
             lit,
             self.line,
-            self.pos_in_line - text.len(),
+            self.pos_in_line - (self.current - self.start),
         ));
     }
 
+    fn text(&self, start: usize, end: usize) -> String {
+        self.source[start..end].iter().collect()
+    }
+
     fn find_match(&mut self, expected: char) -> bool {
         if self.is_at_end() {
             return false;
         }
-        if self.source.chars().nth(self.current).unwrap() != expected {
+        if self.source[self.current] != expected {
             return false;
         }
         self.current += 1;
@@ -165,7 +191,7 @@ impl Scanner {
         if self.is_at_end() {
             return '\0';
         }
-        self.source.chars().nth(self.current).unwrap()
+        self.source[self.current]
     }
 
     fn string(&mut self) -> Result<()> {
@@ -187,7 +213,9 @@ impl Scanner {
         }
 
         self.advance();
-        let value = self.source[self.start + 1..self.current - 1].replace("\\n", "\n");
+        let value = self
+            .text(self.start + 1, self.current - 1)
+            .replace("\\n", "\n");
         self.add_token(TokenType::String, Some(Object::String(value)));
         Ok(())
     }
@@ -217,10 +245,7 @@ impl Scanner {
         self.add_token(
             TokenType::Number,
             Some(Object::Number(
-                self.source[self.start..self.current]
-                    .to_string()
-                    .parse()
-                    .unwrap(),
+                self.text(self.start, self.current).parse().unwrap(),
             )),
         );
         Ok(())
@@ -230,7 +255,7 @@ impl Scanner {
         if self.current + 1 >= self.source.len() {
             return '\0';
         }
-        self.source.chars().nth(self.current + 1).unwrap()
+        self.source[self.current + 1]
     }
 
     fn is_alpha(&self, c: char) -> bool {
@@ -248,7 +273,7 @@ impl Scanner {
             ch = self.peek();
         }
 
-        let text = self.source[self.start..self.current].to_string();
+        let text = self.text(self.start, self.current);
         match self.keywords.get(&text) {
             None => self.add_token(TokenType::Identifier, None),
             Some(ty) => self.add_token(ty.clone(), None),
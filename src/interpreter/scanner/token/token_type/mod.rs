@@ -50,4 +50,17 @@ pub enum TokenType {
     Export,
     LeftBracket,
     RightBracket,
+    Struct,
+    Colon,
+    Enum,
+    Const,
+    Ellipsis,
+    In,
+    DotDot,
+    DotDotEqual,
+    Yield,
+    Async,
+    Await,
+    Pipe,
+    At,
 }
@@ -22,6 +22,15 @@ impl Shell {
     pub fn set_command(&mut self, command: String) {
         self.current_command = command;
     }
+
+    /// Path given to a `:reload [path]` command, empty to reload every
+    /// changed module.
+    pub fn get_reload(&self) -> Option<&str> {
+        self.current_command
+            .strip_prefix(":reload")
+            .filter(|path| path.is_empty() || path.starts_with(' '))
+            .map(str::trim)
+    }
 }
 
 impl AsMut<Shell> for Shell {
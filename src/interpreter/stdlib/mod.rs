@@ -0,0 +1,155 @@
+use crate::interpreter::error::{Result, RuntimeErrorType};
+use crate::interpreter::object::Object;
+use crate::interpreter::object::callable::{CallFn, Callable};
+use crate::rc;
+use crate::utils::next_id;
+use std::cmp::Ordering;
+use std::path::Path;
+use std::rc::Rc;
+
+/// Import paths starting with this prefix name a bundled module.
+pub const PREFIX: &str = "std/";
+
+/// Standard library modules embedded in the binary, by name without prefix.
+const MODULES: [(&str, &str); 3] = [
+    ("math", include_str!("math.yun")),
+    ("lists", include_str!("lists.yun")),
+    ("strings", include_str!("strings.yun")),
+];
+
+pub fn is_std(path: &Path) -> bool {
+    path.to_str().is_some_and(|path| path.starts_with(PREFIX))
+}
+
+/// Source of the bundled module `std/<name>`.
+pub fn source(path: &Path) -> Option<&'static str> {
+    let name = path.to_str()?.strip_prefix(PREFIX)?;
+    MODULES
+        .iter()
+        .find(|(module, _)| *module == name)
+        .map(|(_, source)| *source)
+}
+
+/// Native functions bound in the environment of a bundled module before its
+/// body runs. They are private to the module, which exports them under their
+/// public names.
+pub fn natives(path: &Path) -> Vec<(&'static str, Object)> {
+    match path.to_str().and_then(|path| path.strip_prefix(PREFIX)) {
+        Some("math") => vec![
+            native(
+                "nativeSqrt",
+                "sqrt",
+                1,
+                rc!(|_, args| unary(&args, f64::sqrt)),
+            ),
+            native(
+                "nativeFloor",
+                "floor",
+                1,
+                rc!(|_, args| unary(&args, f64::floor)),
+            ),
+            native(
+                "nativeCeil",
+                "ceil",
+                1,
+                rc!(|_, args| unary(&args, f64::ceil)),
+            ),
+            native(
+                "nativeRound",
+                "round",
+                1,
+                rc!(|_, args| unary(&args, f64::round)),
+            ),
+            native(
+                "nativePow",
+                "pow",
+                2,
+                rc!(|_, args| Ok(Object::Number(number(&args[0])?.powf(number(&args[1])?)))),
+            ),
+        ],
+        Some("lists") => vec![native(
+            "nativeSort",
+            "sort",
+            1,
+            rc!(|_, args| match args[0].inner() {
+                Object::List(values) => {
+                    let mut values = values.clone();
+                    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+                    Ok(Object::List(values))
+                }
+                other => Err(RuntimeErrorType::NotIterable(other.get_type()).into()),
+            }),
+        )],
+        Some("strings") => vec![
+            native(
+                "nativeUpper",
+                "upper",
+                1,
+                rc!(|_, args| Ok(Object::String(string(&args[0])?.to_uppercase()))),
+            ),
+            native(
+                "nativeLower",
+                "lower",
+                1,
+                rc!(|_, args| Ok(Object::String(string(&args[0])?.to_lowercase()))),
+            ),
+            native(
+                "nativeTrim",
+                "trim",
+                1,
+                rc!(|_, args| Ok(Object::String(string(&args[0])?.trim().to_string()))),
+            ),
+            native(
+                "nativeSplit",
+                "split",
+                2,
+                rc!(|_, args| {
+                    let (value, separator) = (string(&args[0])?, string(&args[1])?);
+                    Ok(Object::List(
+                        value
+                            .split(&separator)
+                            .map(|part| Object::String(part.to_string()))
+                            .collect(),
+                    ))
+                }),
+            ),
+        ],
+        _ => vec![],
+    }
+}
+
+fn native(
+    binding: &'static str,
+    name: &'static str,
+    arity: usize,
+    call: CallFn,
+) -> (&'static str, Object) {
+    let callable = Callable::build(
+        next_id(),
+        None,
+        None,
+        call,
+        rc!(move || arity),
+        rc!(move || name.into()),
+        false,
+    );
+    (binding, Object::Callable(callable))
+}
+
+fn unary(args: &[Object], f: fn(f64) -> f64) -> Result<Object> {
+    Ok(Object::Number(f(number(&args[0])?)))
+}
+
+fn number(value: &Object) -> Result<f64> {
+    match value.inner() {
+        Object::Number(number) => Ok(*number),
+        other => Err(RuntimeErrorType::CantToNum(other.get_type()).into()),
+    }
+}
+
+fn string(value: &Object) -> Result<String> {
+    match value.inner() {
+        Object::String(string) => Ok(string.clone()),
+        other => Err(RuntimeErrorType::ExpectedString(other.get_type()).into()),
+    }
+}
@@ -1,15 +1,18 @@
 use crate::cli::Cli;
 use clap::Parser;
-use yun_lib::interpreter::error::Result;
 use yun_lib::interpreter::Interpreter;
+use yun_lib::interpreter::error::Result;
+use yun_lib::interpreter::exporter::loader::FileLoader;
 
 mod cli;
 #[cfg(test)]
 mod test;
 fn main() -> Result<()> {
     let cli = Cli::parse();
+    let interpreter = Interpreter::default()
+        .with_loader(FileLoader::default().with_search_paths(cli.get_include().to_vec()));
     match cli.get_path() {
-        None => Interpreter::default().run_shell(),
-        Some(path_to_script) => Interpreter::default().run_script(path_to_script),
+        None => interpreter.run_shell(),
+        Some(path_to_script) => interpreter.run_script(path_to_script),
     }
-}
\ No newline at end of file
+}
@@ -1,5 +1,9 @@
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 use yun_lib::interpreter::Interpreter;
+use yun_lib::interpreter::error::InterpreterError;
+use yun_lib::interpreter::exporter::loader::{FileLoader, MemoryLoader};
 
 #[test]
 fn hello_world() {
@@ -100,3 +104,404 @@ fn class() {
             .is_ok()
     )
 }
+
+#[test]
+fn structs() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/struct.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn enums() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/enum.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+#[should_panic]
+fn enum_reserved_field() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/enum_reserved_field.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn private_members() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/private.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+#[should_panic]
+fn private_members_access() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/private_access.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn reflection() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/reflection.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn constants() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/const.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+#[should_panic]
+fn constants_reassign() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/const_reassign.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn destructuring() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/destructuring.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn for_in() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/for_in.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn range() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/range.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn slicing() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/slice.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn comprehensions() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/comprehension.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn generators() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/generator.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn async_tasks() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/async.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn pipeline() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/pipeline.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn decorators() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/decorator.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn modules() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/modules.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn selective_imports() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/selective_import.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+#[should_panic]
+fn import_missing_name() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/import_missing.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn transitive_imports() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/transitive_import.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+#[should_panic]
+fn import_cycle() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/import_cycle.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn module_state() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/module_state.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+#[should_panic]
+fn module_private_member() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/module_private.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+#[should_panic]
+fn import_not_found() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/import_not_found.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn search_paths() {
+    assert!(
+        Interpreter::default()
+            .with_loader(
+                FileLoader::default().with_search_paths(vec![PathBuf::from("./examples/vendor")])
+            )
+            .run_test(&PathBuf::from("./examples/search_path.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn std_modules() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/std.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn reexports() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/reexport.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+#[should_panic]
+fn reexported_const_reassign() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/reexport_const.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn packages() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/packages/app"))
+            .is_ok()
+    )
+}
+
+#[test]
+#[should_panic]
+fn packages_missing_entry() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/packages/no_entry"))
+            .is_ok()
+    )
+}
+
+#[test]
+#[should_panic]
+fn packages_stale_lock() {
+    Interpreter::default()
+        .run_test(&PathBuf::from("./examples/packages/stale"))
+        .unwrap()
+}
+
+#[test]
+fn memory_loader() {
+    let loader = MemoryLoader::default()
+        .with_module(
+            "main.yun",
+            r#"
+            use { cube } from "lib/math";
+            use "lib/shapes";
+            if cube(2) != 8 or shapes.unit() != 8 {
+                panic("Unexpected in-memory module result");
+            }
+            "#,
+        )
+        .with_module("lib/math.yun", "export fun cube(n) { return n * n * n; }")
+        .with_module(
+            "lib/shapes/index.yun",
+            r#"use { cube } from "../math"; export fun unit() { return cube(2); }"#,
+        );
+    assert!(
+        Interpreter::default()
+            .with_loader(loader)
+            .run_module(Path::new("main.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+fn memory_loader_missing_module() {
+    let err = Interpreter::default()
+        .with_loader(MemoryLoader::default())
+        .run_module(Path::new("main.yun"))
+        .unwrap_err();
+    assert!(matches!(err, InterpreterError::ExporterError(_)));
+    assert!(err.to_string().contains("Error at 'main.yun'"));
+}
+
+#[test]
+fn hot_reload() {
+    let dir = std::env::temp_dir().join(format!("yun_reload_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let lib = dir.join("lib.yun");
+    fs::write(
+        &lib,
+        "export let state = 1;\nexport fun version() { return 1; }",
+    )
+    .unwrap();
+
+    let mut interpreter = Interpreter::default();
+    let lib_path = dir.join("lib").display().to_string();
+    interpreter
+        .eval(&format!(
+            "use {{ version }} from \"{0}\"; use \"{0}\" as lib;",
+            lib_path
+        ))
+        .unwrap();
+    assert!(!interpreter.reload(&lib).unwrap());
+
+    fs::write(
+        &lib,
+        "export let state = 2;\nexport fun version() { return state; }\nexport fun added() { return 3; }",
+    )
+    .unwrap();
+    fs::File::options()
+        .write(true)
+        .open(&lib)
+        .unwrap()
+        .set_modified(SystemTime::now() + Duration::from_secs(1))
+        .unwrap();
+    assert!(interpreter.reload(&lib).unwrap());
+    let res = interpreter.eval(
+        "if version() != 2 or lib.state != 2 or lib.added() != 3 { panic(\"Stale module\"); }",
+    );
+
+    let lib = lib.canonicalize().unwrap();
+    fs::remove_file(&lib).unwrap();
+    let err = interpreter.reload(&lib).unwrap_err();
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(res.is_ok());
+    assert!(matches!(err, InterpreterError::ExporterError(_)));
+    assert!(err.to_string().contains("Error at 'use'"));
+}
+
+#[test]
+#[should_panic]
+fn const_redeclare() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/const_redeclare.yun"))
+            .is_ok()
+    )
+}
+
+#[test]
+#[should_panic]
+fn slice_fractional_step() {
+    assert!(
+        Interpreter::default()
+            .run_test(&PathBuf::from("./examples/slice_fraction.yun"))
+            .is_ok()
+    )
+}